@@ -1,3 +1,4 @@
+import os
 from collections.abc import Iterator, Mapping
 from enum import Enum
 from typing import Self
@@ -6,7 +7,20 @@ class ArgsError(Exception):
     """Raised when an invalid argument is passed to a function."""
 
 class ParseError(Exception):
-    """Raised when parsing a beatmap or mods string fails."""
+    """
+    Raised when parsing a beatmap or mods string fails.
+
+    Errors of beatmap parsing provide details through their attributes.
+    """
+
+    section: str | None
+    """The section of the .osu file in which parsing failed, e.g. `'HitObjects'`"""
+    line_number: int | None
+    """The one-based number of the line on which parsing failed"""
+    line: str | None
+    """The content of the line on which parsing failed"""
+    causes: list[str]
+    """The error and all of its underlying causes, starting with the outermost one"""
 
 class ConvertError(Exception):
     """Raised when converting a beatmap to another game mode fails."""
@@ -45,25 +59,196 @@ class HitResultGenerator(Enum):
     Fast = 0
     Closest = 1
 
+class HitObjectKind(Enum):
+    """
+    Enum for the kind of a hitobject
+    """
+
+    Circle = 0
+    Slider = 1
+    Spinner = 2
+    Hold = 3
+
+class SuspicionReason(Enum):
+    """
+    Enum for the heuristic that flagged a beatmap as suspicious
+    """
+
+    Density = 0
+    """Notes are too dense time-wise"""
+    Length = 1
+    """The map seems too long"""
+    ObjectCount = 2
+    """Too many objects"""
+    RedFlag = 3
+    """General red flag"""
+    SliderPositions = 4
+    """Too many sliders' positions were suspicious"""
+    SliderRepeats = 5
+    """Too many sliders had a very high amount of repeats"""
+
 class Beatmap:
     """
     Class containing all beatmap data relevant for difficulty and performance calculation
 
     The kwargs must include any of the following:
-        `'path': str`
+        `'path': Union[str, os.PathLike]`
             The path to a .osu file
-        `'content': Union[str, bytearray]`
-            The content of a .osu file as string or bytes
-        `'bytes': bytearray`
-            The content of a .osu file as bytes
+        `'content': Union[str, bytes, bytearray, memoryview]`
+            The content of a .osu file as string or any object supporting the buffer protocol
+        `'bytes': Union[bytes, bytearray, memoryview]`
+            The content of a .osu file as any object supporting the buffer protocol. Contiguous
+            buffers are not copied.
+        `'file': BinaryIO | TextIO`
+            A readable file object such as `io.BufferedReader` or `zipfile.ZipExtFile`
+
+    Optionally, the kwargs may include limits to abort parsing early, e.g. for untrusted input.
+    If any limit is exceeded, a `ParseError` is thrown.
+        `'max_file_size': int`
+            Maximum size of the .osu file in bytes
+        `'max_hit_objects': int`
+            Maximum amount of hitobjects
+        `'max_slider_length': float`
+            Maximum pixel length of sliders as specified in the .osu file
+        `'max_slider_repeats': int`
+            Maximum amount of repeats of a slider
+        `'max_timing_points': int`
+            Maximum amount of timing points, including inherited ones
+
+    Lines of the `[Events]` section other than breaks, e.g. storyboard commands, are not relevant
+    for calculations and are ignored entirely.
+
+    By default, invalid lines are skipped and recorded in `Beatmap.parse_warnings`. To throw a
+    `ParseError` instead, or to emit skipped lines as warnings, the kwargs may include
+        `'strict': bool`
+            Whether parsing should fail on the first invalid line
+        `'warn': bool`
+            Whether skipped lines should be emitted as `UserWarning` through the `warnings`
+            module
 
     ## Raises
 
     Throws an exception if the map could not be parsed or the map's mode cannot be converted to
     the specified mode
+
+    Beatmaps can be pickled, e.g. to send them to other processes through `multiprocessing`.
     """
 
     def __init__(self, **kwargs) -> None: ...
+    @staticmethod
+    def from_osz(
+        src: str | os.PathLike | bytes, **kwargs
+    ) -> dict[str, Beatmap | ParseError]:
+        """
+        Parse all .osu files of an .osz archive, either given as path or as bytes.
+
+        The archive is read through python's `zipfile` module.
+
+        The kwargs may include the same limits and `'strict'` as the `Beatmap` constructor
+        which apply to each .osu file, as well as
+            `'max_total_size': int | None`
+                Maximum total size in bytes of all decompressed .osu files. Defaults to 256 MiB,
+                `None` disables the limit.
+
+        The resulting dict maps file names to their beatmap. If a file could not be parsed, its
+        value is the `ParseError` instead. If the archive contains multiple .osu files of the
+        same name, the value is a `ParseError` as well.
+
+        ## Raises
+
+        Throws a `ParseError` if the archive itself could not be read or if `'max_total_size'`
+        is exceeded
+        """
+
+    @staticmethod
+    def parse_dir(
+        path: str | os.PathLike, recursive: bool = True, threads: int | None = None
+    ) -> dict[str, Beatmap | ParseError]:
+        """
+        Parse all .osu files in a directory in parallel.
+
+        If `recursive` is set, subdirectories are included as well. `threads` defaults to the
+        amount of available CPU cores. The GIL is released while parsing.
+
+        The resulting dict maps file paths to their beatmap. If a file could not be parsed, its
+        value is the `ParseError` instead. Subdirectories that could not be read are included
+        with a `ParseError` as well.
+
+        ## Raises
+
+        Throws an `OSError` if the directory itself could not be read or an `ArgsError` if
+        `threads` is zero or greater than 256
+        """
+
+    @staticmethod
+    def parse_many(
+        paths: list[str | os.PathLike], threads: int | None = None
+    ) -> dict[str, Beatmap | ParseError]:
+        """
+        Parse the .osu files at the given paths in parallel.
+
+        `threads` defaults to the amount of available CPU cores. The GIL is released while
+        parsing.
+
+        The resulting dict maps file paths to their beatmap. If a file could not be parsed, its
+        value is the `ParseError` instead.
+
+        ## Raises
+
+        Throws an `ArgsError` if `threads` is zero or greater than 256
+        """
+
+    @staticmethod
+    def read_info(**kwargs) -> BeatmapInfo:
+        """
+        Only parse the mode, difficulty name and settings, format version, and hit object counts
+        of a .osu file.
+
+        This is considerably faster than parsing the full `Beatmap`, e.g. to index large song
+        folders. Once the `[General]`, `[Metadata]`, and `[Difficulty]` sections have been parsed,
+        everything but the `[HitObjects]` section is skipped and hit objects are only counted
+        by their type, i.e. objects that would be skipped as invalid during full parsing may still
+        be counted.
+
+        The kwargs must include exactly one of `'path'`, `'content'`, `'bytes'`, or `'file'`,
+        just like for the `Beatmap` constructor, and may include the limit `'max_file_size'`.
+
+        ## Raises
+
+        Throws a `ParseError` if the file could not be read
+        """
+
+    def __getstate__(self) -> bytes: ...
+    def __setstate__(self, state: bytes) -> None: ...
+    def bpm_stats(
+        self, mods: GameMods | None = None, clock_rate: float | None = None
+    ) -> BpmStats:
+        """
+        The minimum, maximum, and most common BPM of the map.
+
+        The most common BPM is the one with the longest total duration. Timing points after the
+        last hitobject are ignored. BPM values are adjusted by the clock rate of the mods unless
+        `clock_rate` is specified.
+
+        ## Raises
+
+        Throws an `ArgsError` if `clock_rate` is not a positive number
+        """
+
+    def bpm_timeline(
+        self, mods: GameMods | None = None, clock_rate: float | None = None
+    ) -> list[tuple[float, float]]:
+        """
+        The start time and BPM of each section whose BPM differs from the previous section.
+
+        Timing points after the last hitobject are ignored. Times and BPM values are adjusted by
+        the clock rate of the mods unless `clock_rate` is specified.
+
+        ## Raises
+
+        Throws an `ArgsError` if `clock_rate` is not a positive number
+        """
+
     def convert(self, mode: GameMode, mods: GameMods | None) -> None:
         """
         Convert the beatmap to the specified mode
@@ -73,6 +258,121 @@ class Beatmap:
         Throws an exception if conversion fails or mods are invalid
         """
 
+    def converted(self, mode: GameMode, mods: GameMods | None = None) -> Beatmap:
+        """
+        Return a copy of the beatmap converted to the specified mode, leaving this beatmap
+        untouched
+
+        ## Raises
+
+        Throws an exception if conversion fails or mods are invalid
+        """
+
+    def convert_all(self, mods: GameMods | None = None) -> dict[GameMode, Beatmap]:
+        """
+        Convert the beatmap to all modes it can be converted to
+
+        Unconverted osu!standard beatmaps are converted to all four modes. Other beatmaps only
+        contain a copy for their own mode.
+
+        ## Raises
+
+        Throws an exception if mods are invalid
+        """
+
+    def copy(self) -> Beatmap:
+        """
+        Return a copy of the beatmap
+        """
+
+    def with_clock_rate(self, clock_rate: float) -> Beatmap:
+        """
+        Return a copy of the beatmap that plays at the given clock rate without mods
+
+        All times are divided by the clock rate. AR and OD are adjusted the same way as
+        `BeatmapAttributes` does for the clock rate so that the timing windows stay the
+        same. The new map stores the adjusted values even above 10 but its difficulty and
+        performance calculation caps AR and OD at 10. Whenever the rate pushes either of
+        them above 10, the results are therefore lower than calculating the original map
+        with a `clock_rate`. Otherwise, they only differ by floating point imprecision.
+
+        ## Raises
+
+        Throws an `ArgsError` if the clock rate is not positive
+        """
+
+    def with_attributes(
+        self,
+        *,
+        mods: GameMods | None = None,
+        ar: float | None = None,
+        cs: float | None = None,
+        hp: float | None = None,
+        od: float | None = None,
+    ) -> Beatmap:
+        """
+        Return a copy of the beatmap with overridden difficulty attributes
+
+        Given mods are applied to the attributes the same way as `BeatmapAttributesBuilder`
+        does, e.g. `HR` increases CS. The clock rate of mods is not applied; use
+        `Beatmap.with_clock_rate` instead.
+        """
+
+    def flipped(self) -> Beatmap:
+        """
+        Return a copy of the beatmap with hitobjects flipped vertically like `HR` does
+        """
+
+    def mirrored(self) -> Beatmap:
+        """
+        Return a copy of the beatmap with hitobjects mirrored horizontally
+
+        For osu!mania the columns are mirrored.
+        """
+
+    def slice(self, start: float, end: float) -> Beatmap:
+        """
+        Return a copy of the beatmap that only contains hitobjects starting within
+        `start` (inclusive) and `end` (exclusive) milliseconds
+
+        The result is a standalone beatmap: control points that are active within the
+        range are kept and breaks are clipped to the range.
+        """
+
+    def slice_objects(self, start: int, end: int | None = None) -> Beatmap:
+        """
+        Same as `Beatmap.slice` but the range is given through hitobject indices
+
+        Indices behave like indices of python's slices, e.g. negative indices count from
+        the end.
+        """
+
+    def to_osu_string(self) -> str:
+        """
+        Encode the beatmap in the .osu file format (v14)
+
+        Parsing the result again yields the same difficulty and performance
+        results. Converted beatmaps are written as beatmaps of their new mode.
+
+        ## Raises
+
+        Throws a `ConvertError` for taiko converts. Their difficulty and performance
+        calculation differs from regular taiko maps but the file format cannot mark a
+        beatmap as convert.
+        """
+
+    def write(self, path: str | os.PathLike) -> None:
+        """
+        Write the beatmap as .osu file to the given path
+
+        See `Beatmap.to_osu_string`.
+
+        ## Raises
+
+        Throws a `ConvertError` for taiko converts or an `OSError` if the file could not be
+        written
+        """
+
     def is_suspicious(self) -> bool:
         """
         Check whether hitobjects appear too suspicious for further calculation.
@@ -83,6 +383,12 @@ class Beatmap:
         issues.
         """
 
+    def check_suspicion(self) -> SuspicionReport | None:
+        """
+        Same as `Beatmap.is_suspicious` but returns a report on why the beatmap
+        is suspicious or `None` if it's not suspicious.
+        """
+
     @property
     def bpm(self) -> float: ...
     @property
@@ -117,6 +423,103 @@ class Beatmap:
     def n_spinners(self) -> int: ...
     @property
     def n_holds(self) -> int: ...
+    @property
+    def mania_keys(self) -> int | None:
+        """
+        The key count of an osu!mania map, `None` for other modes.
+
+        To get the key count of a convert, e.g. with a key mod like `"6K"`, convert the map to
+        osu!mania first.
+        """
+
+    @property
+    def mania_columns(self) -> list[ManiaColumn] | None:
+        """
+        The amount of notes and hold notes in each column of an osu!mania map.
+
+        `None` for other modes or if the key count is not supported by osu!mania.
+        """
+
+    @property
+    def mania_ln_ratio(self) -> float | None:
+        """
+        The share of hold notes among all hitobjects of an osu!mania map, `None` for other modes
+        """
+
+    @property
+    def hit_objects(self) -> list[HitObject]:
+        """
+        All hitobjects of the map.
+
+        Each access creates a new list so it's recommended to store it in a
+        variable if it's needed multiple times.
+        """
+
+    @property
+    def timing_points(self) -> list[TimingPoint]:
+        """
+        All timing points of the map i.e. uninherited timing points.
+        """
+
+    @property
+    def difficulty_points(self) -> list[DifficultyPoint]:
+        """
+        All difficulty points of the map i.e. slider velocity changes.
+
+        Points that don't change anything compared to the previous point are
+        not included.
+        """
+
+    @property
+    def effect_points(self) -> list[EffectPoint]:
+        """
+        All effect points of the map i.e. kiai and scroll speed changes.
+
+        Points that don't change anything compared to the previous point are
+        not included.
+        """
+
+    @property
+    def md5(self) -> str | None:
+        """
+        The MD5 hash of the .osu file as lowercase hexadecimal string
+
+        The hash is `None` for converted or otherwise transformed beatmaps since they no
+        longer match the original file. Copies keep the hash.
+        """
+
+    @property
+    def parse_warnings(self) -> list[ParseWarning]:
+        """
+        The invalid lines that were skipped while parsing the .osu file
+        """
+
+    @property
+    def metadata(self) -> BeatmapMetadata:
+        """
+        Metadata and general information of the .osu file
+        """
+
+    @property
+    def breaks(self) -> list[BreakPeriod]: ...
+    @property
+    def total_break_time(self) -> float:
+        """
+        The summed up duration of all breaks in milliseconds.
+        """
+
+    @property
+    def play_length(self) -> float:
+        """
+        Time in milliseconds from the start of the first hitobject to the end
+        of the last hitobject.
+        """
+
+    @property
+    def drain_time(self) -> float:
+        """
+        The play length minus the total break time in milliseconds.
+        """
 
 class Difficulty:
     """
@@ -196,6 +599,56 @@ class Difficulty:
     def calculate(self, map: Beatmap) -> DifficultyAttributes:
         """
         Perform the difficulty calculation
+
+        The GIL is released during the calculation so that multiple calculations can run in
+        parallel on different threads.
+        """
+
+    def calculate_many(
+        self, maps: list[Beatmap], threads: int | None = None
+    ) -> list[DifficultyAttributes]:
+        """
+        Perform the difficulty calculation for each map in parallel
+
+        The attributes are returned in the same order as the maps. `threads` defaults to the
+        amount of available CPU cores. The GIL is released during the calculations.
+
+        ## Raises
+
+        Throws an `ArgsError` if `threads` is zero or greater than 256
+        """
+
+    def calculate_mods(
+        self, map: Beatmap, mods: list[GameMods], threads: int | None = None
+    ) -> list[tuple[GameMods, DifficultyAttributes]]:
+        """
+        Perform the difficulty calculation on the map once for each of the given mods in parallel
+
+        All other settings of the `Difficulty` apply to every calculation, the specified mods
+        are replaced. Each mods value is returned alongside its attributes in the given order.
+        Mods that resolve to the same combination, e.g. `"HDDT"` and `["HD", "DT"]`, are only
+        calculated once. `threads` defaults to the amount of available CPU cores. The GIL is
+        released during the calculations.
+
+        ## Raises
+
+        Throws an exception if any mods are invalid or if `threads` is zero or greater than 256
+        """
+
+    def stars_by_rate(
+        self, map: Beatmap, rates: list[float], threads: int | None = None
+    ) -> list[DifficultyAttributes]:
+        """
+        Perform the difficulty calculation on the map once for each clock rate in parallel
+
+        All other settings of the `Difficulty` apply to every calculation, the clock rate is
+        replaced. Just like for `'clock_rate'`, rates are clamped between 0.01 and 100. The
+        attributes are returned in the same order as the rates. `threads` defaults to the amount
+        of available CPU cores. The GIL is released during the calculations.
+
+        ## Raises
+
+        Throws an `ArgsError` if any rate is not a positive number or if `threads` is zero or greater than 256
         """
 
     def strains(self, map: Beatmap) -> Strains:
@@ -272,6 +725,92 @@ class Difficulty:
     def set_passed_objects(self, passed_objects: int) -> None: ...
     def set_hardrock_offsets(self, hardrock_offsets: bool) -> None: ...
     def set_lazer(self, lazer: bool) -> None: ...
+    @property
+    def mods(self) -> GameMods | None:
+        """
+        The specified mods, as given
+        """
+
+    @property
+    def clock_rate(self) -> float | None:
+        """
+        The specified clock rate
+        """
+
+    @property
+    def ar(self) -> float | None:
+        """
+        The specified approach rate
+        """
+
+    @property
+    def fixed_ar(self) -> bool:
+        """
+        Whether the specified approach rate is fixed
+        """
+
+    @property
+    def cs(self) -> float | None:
+        """
+        The specified circle size
+        """
+
+    @property
+    def fixed_cs(self) -> bool:
+        """
+        Whether the specified circle size is fixed
+        """
+
+    @property
+    def hp(self) -> float | None:
+        """
+        The specified drain rate
+        """
+
+    @property
+    def fixed_hp(self) -> bool:
+        """
+        Whether the specified drain rate is fixed
+        """
+
+    @property
+    def od(self) -> float | None:
+        """
+        The specified overall difficulty
+        """
+
+    @property
+    def fixed_od(self) -> bool:
+        """
+        Whether the specified overall difficulty is fixed
+        """
+
+    @property
+    def passed_objects(self) -> int | None:
+        """
+        The specified amount of passed objects
+        """
+
+    @property
+    def hardrock_offsets(self) -> bool | None:
+        """
+        Whether HR offsets were specified
+        """
+
+    @property
+    def lazer(self) -> bool | None:
+        """
+        Whether lazer was specified
+        """
+
+    def to_dict(self) -> dict[str, object]:
+        """
+        All specified settings as a dict
+
+        The keys are the same as the kwargs of the constructor, so
+        `Difficulty(**difficulty.to_dict())` creates an equivalent
+        `Difficulty`. Settings that were not specified are omitted.
+        """
 
 class Performance:
     """
@@ -419,79 +958,246 @@ class Performance:
         custom ar, ... otherwise the final attributes will be incorrect.
         """
 
-    def difficulty(self) -> Difficulty:
+    def difficulty(self) -> Difficulty:
+        """
+        Use the current difficulty settings to create a difficulty calculator
+        """
+
+    def set_mods(self, mods: GameMods | None) -> None: ...
+    def set_clock_rate(self, clock_rate: float) -> None: ...
+    def set_ar(self, ar: float, fixed: bool) -> None:
+        """
+        Override a beatmap's approach rate.
+
+        Clamped between -20 and 20.
+
+        `fixed` determines if the given AR value should be used before or after
+        accounting for mods, e.g. on `true` the value will be used as is and on
+        `false` it will be modified based on the mods.
+        """
+
+    def set_cs(self, cs: float, fixed: bool) -> None:
+        """
+        Override a beatmap's circle size.
+
+        Clamped between -20 and 20.
+
+        `fixed` determines if the given CS value should be used before or after
+        accounting for mods, e.g. on `true` the value will be used as is and on
+        `false` it will be modified based on the mods.
+        """
+
+    def set_hp(self, hp: float, fixed: bool) -> None:
+        """
+        Override a beatmap's drain rate.
+
+        Clamped between -20 and 20.
+
+        `fixed` determines if the given HP value should be used before or after
+        accounting for mods, e.g. on `true` the value will be used as is and on
+        `false` it will be modified based on the mods.
+        """
+
+    def set_od(self, od: float, fixed: bool) -> None:
+        """
+        Override a beatmap's overall difficulty.
+
+        Clamped between -20 and 20.
+
+        `fixed` determines if the given OD value should be used before or after
+        accounting for mods, e.g. on `true` the value will be used as is and on
+        `false` it will be modified based on the mods.
+        """
+
+    def set_passed_objects(self, passed_objects: int) -> None: ...
+    def set_hardrock_offsets(self, hardrock_offsets: bool) -> None: ...
+    def set_lazer(self, lazer: bool) -> None: ...
+    def set_accuracy(self, accuracy: float | None) -> None: ...
+    def set_combo(self, combo: int | None) -> None: ...
+    def set_large_tick_hits(self, n_large_ticks: int | None) -> None: ...
+    def set_small_tick_hits(self, n_large_ticks: int | None) -> None: ...
+    def set_slider_end_hits(self, n_slider_ends: int | None) -> None: ...
+    def set_n_geki(self, n_geki: int | None) -> None: ...
+    def set_n_katu(self, n_katu: int | None) -> None: ...
+    def set_n300(self, n300: int | None) -> None: ...
+    def set_n100(self, n100: int | None) -> None: ...
+    def set_n50(self, n50: int | None) -> None: ...
+    def set_misses(self, misses: int | None) -> None: ...
+    def set_legacy_total_score(self, legacy_total_score: int | None) -> None: ...
+    def set_hitresult_priority(
+        self, hitresult_priority: HitResultPriority | None
+    ) -> None: ...
+    def set_hitresult_generator(
+        self, hitresult_generator: HitResultGenerator, mode: GameMode | None = None
+    ) -> None: ...
+    @property
+    def mods(self) -> GameMods | None:
+        """
+        The specified mods, as given
+        """
+
+    @property
+    def clock_rate(self) -> float | None:
+        """
+        The specified clock rate
+        """
+
+    @property
+    def ar(self) -> float | None:
+        """
+        The specified approach rate
+        """
+
+    @property
+    def fixed_ar(self) -> bool:
+        """
+        Whether the specified approach rate is fixed
+        """
+
+    @property
+    def cs(self) -> float | None:
+        """
+        The specified circle size
+        """
+
+    @property
+    def fixed_cs(self) -> bool:
+        """
+        Whether the specified circle size is fixed
+        """
+
+    @property
+    def hp(self) -> float | None:
+        """
+        The specified drain rate
+        """
+
+    @property
+    def fixed_hp(self) -> bool:
+        """
+        Whether the specified drain rate is fixed
+        """
+
+    @property
+    def od(self) -> float | None:
+        """
+        The specified overall difficulty
+        """
+
+    @property
+    def fixed_od(self) -> bool:
+        """
+        Whether the specified overall difficulty is fixed
+        """
+
+    @property
+    def passed_objects(self) -> int | None:
+        """
+        The specified amount of passed objects
+        """
+
+    @property
+    def hardrock_offsets(self) -> bool | None:
+        """
+        Whether HR offsets were specified
+        """
+
+    @property
+    def lazer(self) -> bool | None:
+        """
+        Whether lazer was specified
+        """
+
+    @property
+    def accuracy(self) -> float | None:
+        """
+        The specified accuracy
+        """
+
+    @property
+    def combo(self) -> int | None:
+        """
+        The specified combo
+        """
+
+    @property
+    def large_tick_hits(self) -> int | None:
+        """
+        The specified amount of large tick hits
+        """
+
+    @property
+    def small_tick_hits(self) -> int | None:
+        """
+        The specified amount of small tick hits
+        """
+
+    @property
+    def slider_end_hits(self) -> int | None:
+        """
+        The specified amount of slider end hits
+        """
+
+    @property
+    def n_geki(self) -> int | None:
+        """
+        The specified amount of gekis
+        """
+
+    @property
+    def n_katu(self) -> int | None:
+        """
+        The specified amount of katus
+        """
+
+    @property
+    def n300(self) -> int | None:
         """
-        Use the current difficulty settings to create a difficulty calculator
+        The specified amount of 300s
         """
 
-    def set_mods(self, mods: GameMods | None) -> None: ...
-    def set_clock_rate(self, clock_rate: float) -> None: ...
-    def set_ar(self, ar: float, fixed: bool) -> None:
+    @property
+    def n100(self) -> int | None:
         """
-        Override a beatmap's approach rate.
-
-        Clamped between -20 and 20.
-
-        `fixed` determines if the given AR value should be used before or after
-        accounting for mods, e.g. on `true` the value will be used as is and on
-        `false` it will be modified based on the mods.
+        The specified amount of 100s
         """
 
-    def set_cs(self, cs: float, fixed: bool) -> None:
+    @property
+    def n50(self) -> int | None:
         """
-        Override a beatmap's circle size.
-
-        Clamped between -20 and 20.
-
-        `fixed` determines if the given CS value should be used before or after
-        accounting for mods, e.g. on `true` the value will be used as is and on
-        `false` it will be modified based on the mods.
+        The specified amount of 50s
         """
 
-    def set_hp(self, hp: float, fixed: bool) -> None:
+    @property
+    def misses(self) -> int | None:
+        """
+        The specified amount of misses
         """
-        Override a beatmap's drain rate.
 
-        Clamped between -20 and 20.
+    @property
+    def legacy_total_score(self) -> int | None:
+        """
+        The specified legacy total score
+        """
 
-        `fixed` determines if the given HP value should be used before or after
-        accounting for mods, e.g. on `true` the value will be used as is and on
-        `false` it will be modified based on the mods.
+    @property
+    def hitresult_priority(self) -> HitResultPriority:
+        """
+        The specified hitresult priority
         """
 
-    def set_od(self, od: float, fixed: bool) -> None:
+    def to_dict(self) -> dict[str, object]:
         """
-        Override a beatmap's overall difficulty.
+        All specified settings as a dict
 
-        Clamped between -20 and 20.
+        The keys are the same as the kwargs of the constructor, so
+        `Performance(**performance.to_dict())` creates an equivalent
+        `Performance`. Settings that were not specified are omitted except
+        for `'hitresult_priority'` which is always included.
 
-        `fixed` determines if the given OD value should be used before or after
-        accounting for mods, e.g. on `true` the value will be used as is and on
-        `false` it will be modified based on the mods.
+        Hitresult generators are not included.
         """
 
-    def set_passed_objects(self, passed_objects: int) -> None: ...
-    def set_hardrock_offsets(self, hardrock_offsets: bool) -> None: ...
-    def set_lazer(self, lazer: bool) -> None: ...
-    def set_accuracy(self, accuracy: float | None) -> None: ...
-    def set_combo(self, combo: int | None) -> None: ...
-    def set_large_tick_hits(self, n_large_ticks: int | None) -> None: ...
-    def set_small_tick_hits(self, n_large_ticks: int | None) -> None: ...
-    def set_slider_end_hits(self, n_slider_ends: int | None) -> None: ...
-    def set_n_geki(self, n_geki: int | None) -> None: ...
-    def set_n_katu(self, n_katu: int | None) -> None: ...
-    def set_n300(self, n300: int | None) -> None: ...
-    def set_n100(self, n100: int | None) -> None: ...
-    def set_n50(self, n50: int | None) -> None: ...
-    def set_misses(self, misses: int | None) -> None: ...
-    def set_legacy_total_score(self, legacy_total_score: int | None) -> None: ...
-    def set_hitresult_priority(
-        self, hitresult_priority: HitResultPriority | None
-    ) -> None: ...
-    def set_hitresult_generator(
-        self, hitresult_generator: HitResultGenerator, mode: GameMode | None = None
-    ) -> None: ...
-
 class GradualDifficulty(Iterator):
     """
     Gradually calculate difficulty attributes after each hitobject
@@ -1346,3 +2052,310 @@ class BeatmapAttributes:
 
         Only available for osu! and osu!mania.
         """
+
+class HitObject:
+    """
+    A hitobject of a `Beatmap`.
+    """
+
+    @property
+    def kind(self) -> HitObjectKind: ...
+    @property
+    def start_time(self) -> float:
+        """
+        Start time of the hitobject in milliseconds.
+        """
+
+    @property
+    def end_time(self) -> float:
+        """
+        End time of the hitobject in milliseconds.
+
+        For circles this is the same as `start_time`.
+        """
+
+    @property
+    def x(self) -> float: ...
+    @property
+    def y(self) -> float: ...
+    @property
+    def repeats(self) -> int | None:
+        """
+        The amount of repeats of a slider.
+
+        Only available for sliders.
+        """
+
+    @property
+    def length(self) -> float | None:
+        """
+        The length of a slider's path.
+
+        Only available for sliders.
+        """
+
+    @property
+    def control_points(self) -> list[PathControlPoint] | None:
+        """
+        The control points of a slider's path.
+
+        Only available for sliders.
+        """
+
+class PathControlPoint:
+    """
+    A control point of a slider's path.
+    """
+
+    @property
+    def x(self) -> float:
+        """
+        The x coordinate relative to the slider's position.
+        """
+
+    @property
+    def y(self) -> float:
+        """
+        The y coordinate relative to the slider's position.
+        """
+
+    @property
+    def path_type(self) -> str | None:
+        """
+        The path type starting at this control point, written the same way as
+        in `.osu` files i.e. `"B"` (optionally followed by a degree), `"C"`,
+        `"L"`, or `"P"`.
+        """
+
+class TimingPoint:
+    """
+    An uninherited timing point of a `Beatmap`.
+    """
+
+    @property
+    def time(self) -> float: ...
+    @property
+    def beat_len(self) -> float:
+        """
+        Duration of a beat in milliseconds.
+        """
+
+    @property
+    def bpm(self) -> float: ...
+    @property
+    def meter(self) -> int:
+        """
+        Amount of beats per measure.
+        """
+
+class DifficultyPoint:
+    """
+    A difficulty point of a `Beatmap`, usually caused by an inherited timing point.
+    """
+
+    @property
+    def time(self) -> float: ...
+    @property
+    def slider_velocity(self) -> float:
+        """
+        Slider velocity multiplier.
+        """
+
+    @property
+    def bpm_multiplier(self) -> float: ...
+    @property
+    def generate_ticks(self) -> bool:
+        """
+        Whether sliders generate ticks in this section.
+        """
+
+class EffectPoint:
+    """
+    An effect point of a `Beatmap`.
+    """
+
+    @property
+    def time(self) -> float: ...
+    @property
+    def kiai(self) -> bool: ...
+    @property
+    def scroll_speed(self) -> float:
+        """
+        Scroll speed multiplier.
+
+        Only relevant for osu!taiko and osu!mania.
+        """
+
+class BeatmapInfo:
+    """
+    Basic information of a .osu file, see `Beatmap.read_info`.
+    """
+
+    @property
+    def mode(self) -> GameMode: ...
+    @property
+    def format_version(self) -> int:
+        """
+        The version of the .osu file format
+        """
+
+    @property
+    def difficulty_name(self) -> str: ...
+    @property
+    def ar(self) -> float: ...
+    @property
+    def cs(self) -> float: ...
+    @property
+    def hp(self) -> float: ...
+    @property
+    def od(self) -> float: ...
+    @property
+    def n_objects(self) -> int: ...
+    @property
+    def n_circles(self) -> int: ...
+    @property
+    def n_sliders(self) -> int: ...
+    @property
+    def n_spinners(self) -> int: ...
+    @property
+    def n_holds(self) -> int: ...
+
+class ManiaColumn:
+    """
+    Object counts of a column of an osu!mania `Beatmap`.
+    """
+
+    @property
+    def column(self) -> int:
+        """The zero-based index of the column"""
+    @property
+    def notes(self) -> int:
+        """The amount of regular notes"""
+    @property
+    def holds(self) -> int:
+        """The amount of hold notes"""
+
+class BeatmapMetadata:
+    """
+    Metadata of a `Beatmap` that is irrelevant for difficulty and performance calculation.
+    """
+
+    @property
+    def title(self) -> str: ...
+    @property
+    def title_unicode(self) -> str: ...
+    @property
+    def artist(self) -> str: ...
+    @property
+    def artist_unicode(self) -> str: ...
+    @property
+    def creator(self) -> str: ...
+    @property
+    def version(self) -> str:
+        """
+        The difficulty name
+        """
+
+    @property
+    def source(self) -> str: ...
+    @property
+    def tags(self) -> str:
+        """
+        Space separated tags
+        """
+
+    @property
+    def beatmap_id(self) -> int:
+        """
+        The beatmap id or -1 if unspecified
+        """
+
+    @property
+    def beatmap_set_id(self) -> int: ...
+    @property
+    def audio_file(self) -> str: ...
+    @property
+    def audio_lead_in(self) -> float: ...
+    @property
+    def preview_time(self) -> int:
+        """
+        Milliseconds at which the audio preview starts or -1 if unspecified
+        """
+
+class BpmStats:
+    """
+    BPM statistics of a `Beatmap`, see `Beatmap.bpm_stats`.
+    """
+
+    @property
+    def min(self) -> float: ...
+    @property
+    def max(self) -> float: ...
+    @property
+    def most_common(self) -> float: ...
+    @property
+    def clock_rate(self) -> float:
+        """
+        The clock rate that the BPM values are adjusted by
+        """
+
+class BreakPeriod:
+    """
+    A break of a `Beatmap`.
+    """
+
+    @property
+    def start_time(self) -> float: ...
+    @property
+    def end_time(self) -> float: ...
+    @property
+    def duration(self) -> float: ...
+
+class ParseWarning:
+    """
+    An invalid line of a .osu file that was skipped while parsing a `Beatmap`.
+    """
+
+    @property
+    def section(self) -> str:
+        """The section of the line, e.g. `'HitObjects'`"""
+    @property
+    def line_number(self) -> int:
+        """The one-based number of the line"""
+    @property
+    def line(self) -> str:
+        """The content of the line"""
+    @property
+    def reason(self) -> str:
+        """Why the line is invalid"""
+
+class SuspicionReport:
+    """
+    Describes why a `Beatmap` is considered suspicious.
+    """
+
+    @property
+    def reason(self) -> SuspicionReason: ...
+    @property
+    def value(self) -> float:
+        """
+        The offending value e.g. the amount of objects or the map length in milliseconds
+        """
+
+    @property
+    def threshold(self) -> float:
+        """
+        The limit that `value` exceeded
+        """
+
+    @property
+    def time(self) -> float | None:
+        """
+        Timestamp in milliseconds at which the issue occurred, if applicable
+        """
+
+    @property
+    def message(self) -> str:
+        """
+        Human-readable description of the issue
+        """
@@ -0,0 +1,160 @@
+//! Processing of many items in parallel.
+
+use std::{
+    fs, io,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use pyo3::PyResult;
+
+use crate::error::ArgsError;
+
+/// Maximum amount of threads that may be given as argument.
+pub const MAX_THREADS: usize = 256;
+
+/// Validates the amount of threads given as argument.
+pub fn threads(threads: Option<usize>) -> PyResult<Option<NonZeroUsize>> {
+    match threads {
+        Some(threads) if threads > MAX_THREADS => Err(ArgsError::new_err(format!(
+            "threads: must be at most {MAX_THREADS}"
+        ))),
+        Some(threads) => NonZeroUsize::new(threads)
+            .map(Some)
+            .ok_or_else(|| ArgsError::new_err("threads: must be positive")),
+        None => Ok(None),
+    }
+}
+
+/// Applies `f` to all items on up to `threads` threads and returns the
+/// results in the same order as the items.
+///
+/// Items are handed out one at a time so that expensive items don't hold up
+/// threads that are already done.
+pub fn map<T, R, F>(items: &[T], threads: Option<NonZeroUsize>, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let threads = threads
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get)
+        .min(items.len());
+
+    if threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+
+    let work = || {
+        let mut results = Vec::new();
+
+        loop {
+            let idx = next.fetch_add(1, Ordering::Relaxed);
+
+            let Some(item) = items.get(idx) else {
+                return results;
+            };
+
+            results.push((idx, f(item)));
+        }
+    };
+
+    let mut results: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads).map(|_| scope.spawn(work)).collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|err| std::panic::resume_unwind(err))
+            })
+            .collect()
+    });
+
+    results.sort_unstable_by_key(|(idx, _)| *idx);
+
+    results.into_iter().map(|(_, res)| res).collect()
+}
+
+/// The `.osu` files of a directory.
+#[derive(Default)]
+pub struct OsuFiles {
+    /// Paths of all `.osu` files, sorted by path.
+    pub paths: Vec<PathBuf>,
+    /// Subdirectories or entries that could not be read, sorted by path.
+    pub unreadable: Vec<(PathBuf, io::Error)>,
+}
+
+/// Collects the paths of all `.osu` files in a directory.
+///
+/// Only failing to read `dir` itself is an error; failures within it are
+/// recorded in [`OsuFiles::unreadable`].
+pub fn osu_files(dir: &Path, recursive: bool) -> io::Result<OsuFiles> {
+    fn collect(dir: &Path, recursive: bool, files: &mut OsuFiles) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    files.unreadable.push((dir.to_owned(), err));
+
+                    continue;
+                }
+            };
+
+            let path = entry.path();
+
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(err) => {
+                    files.unreadable.push((path, err));
+
+                    continue;
+                }
+            };
+
+            if file_type.is_dir() {
+                if recursive {
+                    if let Err(err) = collect(&path, recursive, files) {
+                        files.unreadable.push((path, err));
+                    }
+                }
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("osu"))
+            {
+                files.paths.push(path);
+            }
+        }
+
+        Ok(())
+    }
+
+    let mut files = OsuFiles::default();
+    collect(dir, recursive, &mut files)?;
+    files.paths.sort_unstable();
+    files.unreadable.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let items: Vec<u32> = (0..1000).collect();
+
+        for threads in [1, 3, 16] {
+            let results = map(&items, NonZeroUsize::new(threads), |n| n * 2);
+
+            assert!(results.iter().copied().eq(items.iter().map(|n| n * 2)));
+        }
+    }
+}
@@ -1,85 +1,325 @@
-use std::{error::Error as StdError, fmt::Write};
+use std::{
+    error::Error as StdError,
+    ffi::CString,
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
 
 use pyo3::{
-    exceptions::PyTypeError,
-    pyclass, pymethods,
-    types::{PyAnyMethods, PyDict},
-    Bound, Py, PyAny, PyResult, Python,
+    exceptions::{PyBaseException, PyTypeError, PyUserWarning},
+    intern, pyclass, pymethods,
+    types::{PyAnyMethods, PyBytes, PyDict, PyDictMethods, PyType},
+    Bound, Py, PyAny, PyErr, PyResult, Python,
 };
+use rosu_map::section::hit_objects::CurveBuffers;
 use rosu_pp::{
-    model::{hit_object::HitObjectKind, mode::GameMode},
+    model::{
+        beatmap::{AdjustedBeatmapAttributes, BeatmapAttributesBuilder},
+        hit_object::HitObjectKind,
+        mode::GameMode,
+    },
     Beatmap,
 };
 
 use crate::{
-    error::{ArgsError, ConvertError, ParseError},
+    batch::{self, OsuFiles},
+    bpm::{self, PyBpmStats},
+    break_period::PyBreakPeriod,
+    buffer::{self, BufferBytes},
+    control_point::{PyDifficultyPoint, PyEffectPoint, PyTimingPoint},
+    decode::{self, DecodeError, DecodedBeatmap, LineLocation, ParseLimits, ParseOptions},
+    encode,
+    error::{parse_error_from_msg, ArgsError, ConvertError, ParseError},
+    hit_object::{self, PyHitObject},
+    info::{self, PyBeatmapInfo},
+    mania::{self, PyManiaColumn},
+    md5,
+    metadata::PyBeatmapMetadata,
     mode::PyGameMode,
     mods::PyGameMods,
+    osz::{self, OszError},
+    parse_warning::PyParseWarning,
+    pickle::{self, UnpickleError},
+    suspicion::PySuspicionReport,
+    transform,
 };
 
-#[pyclass(name = "Beatmap")]
+#[pyclass(name = "Beatmap", module = "rosu_pp_py", skip_from_py_object)]
+#[derive(Clone)]
 pub struct PyBeatmap {
     pub(crate) inner: Beatmap,
+    pub(crate) time_signatures: Vec<(f64, i32)>,
+    pub(crate) metadata: PyBeatmapMetadata,
+    /// MD5 digest of the `.osu` file; `None` if the map was transformed.
+    pub(crate) md5: Option<String>,
+    pub(crate) parse_warnings: Vec<PyParseWarning>,
+}
+
+impl PyBeatmap {
+    pub(crate) fn decoded(decoded: DecodedBeatmap, md5: String) -> Self {
+        Self {
+            inner: decoded.map,
+            time_signatures: decoded.time_signatures,
+            metadata: decoded.metadata,
+            md5: Some(md5),
+            parse_warnings: decoded
+                .warnings
+                .into_iter()
+                .map(PyParseWarning::from)
+                .collect(),
+        }
+    }
 }
 
 #[pymethods]
 impl PyBeatmap {
     #[new]
     #[pyo3(signature = (**kwargs))]
-    fn new(kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+    fn new(kwargs: Option<&Bound<'_, PyDict>>, py: Python<'_>) -> PyResult<Self> {
         let Some(kwargs) = kwargs else {
             return Err(ArgsError::new_err(
-                "kwarg 'path', 'bytes', or 'content' must be specified",
+                "kwarg 'path', 'bytes', 'content', or 'file' must be specified",
             ));
         };
 
-        let mut map_res = None;
+        let mut src = None;
+        let mut options = ParseOptions::default();
+        let limits = &mut options.limits;
+        let mut warn = false;
 
         for (key, value) in kwargs {
             extract_args! {
                 match key {
-                    "path" => {
-                        let path: &str = extract!(path = value as "str");
-                        map_res = Some(Beatmap::from_path(path));
-                    },
-                    "content" => {
-                        let bytes = if let Ok(content) = value.extract::<&str>() {
-                            content.as_bytes()
-                        } else {
-                            extract!(content = value as "str or bytearray")
-                        };
-
-                        map_res = Some(Beatmap::from_bytes(bytes));
-                    },
-                    "bytes" => {
-                        let bytes = extract!(bytes = value as "bytearray");
-                        map_res = Some(Beatmap::from_bytes(bytes));
-                    },
+                    "path" => src = Some(("path", value)),
+                    "content" => src = Some(("content", value)),
+                    "bytes" => src = Some(("bytes", value)),
+                    "file" => src = Some(("file", value)),
+                    "max_file_size" => limits.max_file_size =
+                        extract!(max_file_size = value as "Optional[int]"),
+                    "max_hit_objects" => limits.max_hit_objects =
+                        extract!(max_hit_objects = value as "Optional[int]"),
+                    "max_slider_length" => limits.max_slider_length =
+                        extract!(max_slider_length = value as "Optional[float]"),
+                    "max_slider_repeats" => limits.max_slider_repeats =
+                        extract!(max_slider_repeats = value as "Optional[int]"),
+                    "max_timing_points" => limits.max_timing_points =
+                        extract!(max_timing_points = value as "Optional[int]"),
+                    "strict" => options.strict = extract!(strict = value as "bool"),
+                    "warn" => warn = extract!(warn = value as "bool"),
                 }
             }
         }
 
-        let map = match map_res {
-            Some(Ok(map)) => map,
-            Some(Err(err)) => {
-                let mut e = &err as &dyn StdError;
-                let mut content = format!("Failed to parse beatmap\n  - caused by: {e}");
-
-                while let Some(src) = e.source() {
-                    let _ = write!(content, "\n  - caused by: {src}");
-                    e = src;
-                }
+        // Limits need to be known before parsing so the source is handled
+        // after all kwargs have been extracted
+        let map_res = read_source(src, &options.limits, |bytes| {
+            decode::from_bytes(bytes, &options).map(|decoded| (decoded, md5::hex_digest(bytes)))
+        })?;
 
-                return Err(ParseError::new_err(content));
-            }
+        let (decoded, md5) = match map_res {
+            Some(Ok(decoded)) => decoded,
+            Some(Err(err)) => return Err(parse_error(py, &err)),
             None => {
                 return Err(ArgsError::new_err(
-                    "kwarg 'path', 'bytes', or 'content' must be specified",
+                    "kwarg 'path', 'bytes', 'content', or 'file' must be specified",
                 ))
             }
         };
 
-        Ok(Self { inner: map })
+        let map = Self::decoded(decoded, md5);
+
+        if warn {
+            map.emit_parse_warnings(py)?;
+        }
+
+        Ok(map)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (**kwargs))]
+    fn read_info(kwargs: Option<&Bound<'_, PyDict>>, py: Python<'_>) -> PyResult<PyBeatmapInfo> {
+        let mut src = None;
+        let mut limits = ParseLimits::default();
+
+        for (key, value) in kwargs.into_iter().flatten() {
+            extract_args! {
+                match key {
+                    "path" => src = Some(("path", value)),
+                    "content" => src = Some(("content", value)),
+                    "bytes" => src = Some(("bytes", value)),
+                    "file" => src = Some(("file", value)),
+                    "max_file_size" => limits.max_file_size =
+                        extract!(max_file_size = value as "Optional[int]"),
+                }
+            }
+        }
+
+        let info_res = read_source(src, &limits, |bytes| {
+            info::from_bytes(bytes, &limits).map_err(DecodeError::from)
+        })?;
+
+        match info_res {
+            Some(Ok(info)) => Ok(info),
+            Some(Err(err)) => Err(parse_error(py, &err)),
+            None => Err(ArgsError::new_err(
+                "kwarg 'path', 'bytes', 'content', or 'file' must be specified",
+            )),
+        }
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (path, recursive = true, threads = None))]
+    fn parse_dir<'py>(
+        path: PathBuf,
+        recursive: bool,
+        threads: Option<usize>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let threads = batch::threads(threads)?;
+
+        let (paths, results) = py.detach(|| {
+            let OsuFiles {
+                mut paths,
+                unreadable,
+            } = batch::osu_files(&path, recursive)?;
+
+            let mut results = batch::map(&paths, threads, |path| decode_path(path));
+
+            for (path, err) in unreadable {
+                paths.push(path);
+                results.push(Err(DecodeError::Io(err)));
+            }
+
+            Ok::<_, io::Error>((paths, results))
+        })?;
+
+        Self::batch_dict(py, &paths, results)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (paths, threads = None))]
+    fn parse_many<'py>(
+        paths: Vec<PathBuf>,
+        threads: Option<usize>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let threads = batch::threads(threads)?;
+
+        let results = py.detach(|| batch::map(&paths, threads, |path| decode_path(path)));
+
+        Self::batch_dict(py, &paths, results)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (src, **kwargs))]
+    fn from_osz<'py>(
+        src: &Bound<'py, PyAny>,
+        kwargs: Option<&Bound<'py, PyDict>>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let mut options = ParseOptions::default();
+        let limits = &mut options.limits;
+        let mut max_total_size = Some(osz::DEFAULT_MAX_TOTAL_SIZE);
+
+        for (key, value) in kwargs.into_iter().flatten() {
+            extract_args! {
+                match key {
+                    "max_file_size" => limits.max_file_size =
+                        extract!(max_file_size = value as "Optional[int]"),
+                    "max_hit_objects" => limits.max_hit_objects =
+                        extract!(max_hit_objects = value as "Optional[int]"),
+                    "max_slider_length" => limits.max_slider_length =
+                        extract!(max_slider_length = value as "Optional[float]"),
+                    "max_slider_repeats" => limits.max_slider_repeats =
+                        extract!(max_slider_repeats = value as "Optional[int]"),
+                    "max_timing_points" => limits.max_timing_points =
+                        extract!(max_timing_points = value as "Optional[int]"),
+                    "max_total_size" => max_total_size =
+                        extract!(max_total_size = value as "Optional[int]"),
+                    "strict" => options.strict = extract!(strict = value as "bool"),
+                }
+            }
+        }
+
+        let archive_src = if BufferBytes::extract(src).is_some() {
+            py.import(intern!(py, "io"))?
+                .getattr(intern!(py, "BytesIO"))?
+                .call1((src,))?
+        } else {
+            src.extract::<PathBuf>().map_err(|_| {
+                PyTypeError::new_err("src: must be str, os.PathLike, or bytes-like")
+            })?;
+
+            src.clone()
+        };
+
+        let archive = py
+            .import(intern!(py, "zipfile"))?
+            .getattr(intern!(py, "ZipFile"))?
+            .call1((archive_src,))
+            .map_err(|err| parse_osz_error(py, &OszError::Archive(err)))?;
+
+        let entries = osz::osu_files(&archive, &options.limits, max_total_size);
+        archive.call_method0(intern!(py, "close"))?;
+        let entries = entries.map_err(|err| parse_osz_error(py, &err))?;
+
+        let maps = PyDict::new(py);
+
+        for entry in entries {
+            let map_res = entry
+                .content
+                .map_err(|err| parse_error(py, &err))
+                .and_then(|bytes| match decode::from_bytes(&bytes, &options) {
+                    Ok(decoded) => Ok(Self::decoded(decoded, md5::hex_digest(&bytes))),
+                    Err(err) => Err(parse_error(py, &err)),
+                });
+
+            match map_res {
+                Ok(map) => maps.set_item(&entry.name, map)?,
+                Err(err) => maps.set_item(&entry.name, err.into_value(py))?,
+            }
+        }
+
+        Ok(maps)
+    }
+
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyBytes>,))> {
+        let py = slf.py();
+
+        // Unpickling creates the beatmap directly from the state instead of
+        // going through the constructor
+        let from_state = slf.get_type().getattr(intern!(py, "_from_state"))?;
+        let state = PyBytes::new(py, &pickle::serialize(&slf.borrow()));
+
+        Ok((from_state, (state,)))
+    }
+
+    #[classmethod]
+    fn _from_state(cls: &Bound<'_, PyType>, state: &[u8]) -> PyResult<Self> {
+        pickle::deserialize(state).map_err(|err| unpickle_error(cls.py(), err))
+    }
+
+    fn __getstate__<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &pickle::serialize(self))
+    }
+
+    fn __setstate__(&mut self, py: Python<'_>, state: &[u8]) -> PyResult<()> {
+        *self = pickle::deserialize(state).map_err(|err| unpickle_error(py, err))?;
+
+        Ok(())
+    }
+
+    fn to_osu_string(&self) -> PyResult<String> {
+        self.encode()
+    }
+
+    fn write(&self, path: PathBuf) -> PyResult<()> {
+        fs::write(path, self.encode()?)?;
+
+        Ok(())
     }
 
     #[pyo3(signature = (mode, mods=None))]
@@ -89,20 +329,171 @@ impl PyBeatmap {
         mods: Option<Py<PyAny>>,
         py: Python<'_>,
     ) -> PyResult<()> {
-        let mods = match PyGameMods::extract(mods.as_ref(), mode.into(), py) {
-            Ok(PyGameMods::Lazer(mods)) => mods.into(),
-            Ok(PyGameMods::Intermode(mods)) => mods.into(),
-            Ok(PyGameMods::Legacy(mods)) => mods.into(),
+        convert_map(&mut self.inner, mode, mods.as_ref(), py)?;
+        self.md5 = None;
+
+        Ok(())
+    }
+
+    #[pyo3(signature = (mode, mods=None))]
+    fn converted(
+        &self,
+        mode: PyGameMode,
+        mods: Option<Py<PyAny>>,
+        py: Python<'_>,
+    ) -> PyResult<Self> {
+        let mut map = self.transformed();
+        convert_map(&mut map.inner, mode, mods.as_ref(), py)?;
+
+        Ok(map)
+    }
+
+    #[pyo3(signature = (mods=None))]
+    fn convert_all<'py>(
+        &self,
+        mods: Option<Py<PyAny>>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let converted = PyDict::new(py);
+
+        for mode in [
+            PyGameMode::Osu,
+            PyGameMode::Taiko,
+            PyGameMode::Catch,
+            PyGameMode::Mania,
+        ] {
+            // Only unconverted osu!standard maps can be converted to other modes
+            let convertible = self.inner.mode == GameMode::from(mode)
+                || (self.inner.mode == GameMode::Osu && !self.inner.is_convert);
+
+            if convertible {
+                let mut map = self.transformed();
+                convert_map(&mut map.inner, mode, mods.as_ref(), py)?;
+                converted.set_item(mode, map)?;
+            }
+        }
+
+        Ok(converted)
+    }
+
+    fn copy(&self) -> Self {
+        self.clone()
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+
+    #[pyo3(signature = (clock_rate))]
+    fn with_clock_rate(&self, clock_rate: f64) -> PyResult<Self> {
+        if !(clock_rate.is_finite() && clock_rate > 0.0) {
+            return Err(ArgsError::new_err("clock rate must be a positive number"));
+        }
+
+        let AdjustedBeatmapAttributes { ar, od, .. } = BeatmapAttributesBuilder::new()
+            .map(&self.inner)
+            .clock_rate(clock_rate)
+            .build()
+            .apply_clock_rate();
+
+        let mut map = self.transformed();
+        transform::scale_time(&mut map, clock_rate);
+        map.inner.ar = ar as f32;
+        map.inner.od = od as f32;
+
+        Ok(map)
+    }
+
+    #[pyo3(signature = (**kwargs))]
+    fn with_attributes(
+        &self,
+        kwargs: Option<&Bound<'_, PyDict>>,
+        py: Python<'_>,
+    ) -> PyResult<Self> {
+        let mut builder = BeatmapAttributesBuilder::new();
+        builder.map(&self.inner);
+
+        let mut mods = None;
+
+        if let Some(kwargs) = kwargs {
+            for (key, value) in kwargs {
+                extract_args! {
+                    match key {
+                        "mods" => mods = Some(extract!(mods = value as "type that matches GameMods alias")),
+                        "ar" => { builder.ar(extract!(ar = value as "float"), false); },
+                        "cs" => { builder.cs(extract!(cs = value as "float"), false); },
+                        "hp" => { builder.hp(extract!(hp = value as "float"), false); },
+                        "od" => { builder.od(extract!(od = value as "float"), false); },
+                    }
+                }
+            }
+        }
+
+        match PyGameMods::extract(mods.as_ref(), self.inner.mode, py) {
+            Ok(PyGameMods::Lazer(mods)) => builder.mods(mods),
+            Ok(PyGameMods::Intermode(ref mods)) => builder.mods(mods),
+            Ok(PyGameMods::Legacy(mods)) => builder.mods(mods),
             Err(err) => return Err(err),
         };
 
-        let mode = GameMode::from(mode);
+        // The clock rate of mods is not applied; see `with_clock_rate`
+        let attrs = builder.build();
 
-        if let Err(err) = self.inner.convert_mut(mode, &mods) {
-            return Err(ConvertError::new_err(err.to_string()));
-        }
+        let mut map = self.transformed();
+        map.inner.ar = attrs.ar();
+        map.inner.cs = attrs.cs();
+        map.inner.hp = attrs.hp();
+        map.inner.od = attrs.od();
 
-        Ok(())
+        Ok(map)
+    }
+
+    fn flipped(&self) -> Self {
+        let mut map = self.transformed();
+        transform::flip_vertical(&mut map);
+
+        map
+    }
+
+    fn mirrored(&self) -> Self {
+        let mut map = self.transformed();
+        transform::mirror_horizontal(&mut map);
+
+        map
+    }
+
+    #[pyo3(signature = (start, end))]
+    fn slice(&self, start: f64, end: f64) -> Self {
+        let mut map = self.transformed();
+        transform::slice(&mut map, start, end);
+
+        map
+    }
+
+    #[pyo3(signature = (start, end=None))]
+    fn slice_objects(&self, start: isize, end: Option<isize>) -> Self {
+        let len = self.inner.hit_objects.len();
+
+        // Same semantics as python's slice indices
+        let normalize = |idx: isize| {
+            if idx < 0 {
+                len.saturating_sub(idx.unsigned_abs())
+            } else {
+                idx.unsigned_abs().min(len)
+            }
+        };
+
+        let start = normalize(start);
+        let end = end.map_or(len, normalize).max(start);
+
+        let mut map = self.transformed();
+        transform::slice_objects(&mut map, start..end);
+
+        map
     }
 
     #[pyo3()]
@@ -110,11 +501,42 @@ impl PyBeatmap {
         self.inner.check_suspicion().is_err()
     }
 
+    fn check_suspicion(&self) -> Option<PySuspicionReport> {
+        self.inner
+            .check_suspicion()
+            .err()
+            .map(|reason| PySuspicionReport::new(reason, &self.inner))
+    }
+
     #[getter]
     fn bpm(&self) -> f64 {
         self.inner.bpm()
     }
 
+    #[pyo3(signature = (mods=None, clock_rate=None))]
+    fn bpm_stats(
+        &self,
+        mods: Option<Py<PyAny>>,
+        clock_rate: Option<f64>,
+        py: Python<'_>,
+    ) -> PyResult<PyBpmStats> {
+        let clock_rate = self.clock_rate(mods.as_ref(), clock_rate, py)?;
+
+        Ok(bpm::stats(&self.inner, clock_rate))
+    }
+
+    #[pyo3(signature = (mods=None, clock_rate=None))]
+    fn bpm_timeline(
+        &self,
+        mods: Option<Py<PyAny>>,
+        clock_rate: Option<f64>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<(f64, f64)>> {
+        let clock_rate = self.clock_rate(mods.as_ref(), clock_rate, py)?;
+
+        Ok(bpm::timeline(&self.inner, clock_rate))
+    }
+
     #[getter]
     fn version(&self) -> i32 {
         self.inner.version
@@ -210,4 +632,330 @@ impl PyBeatmap {
             .filter(|h| matches!(h.kind, HitObjectKind::Hold(_)))
             .count()
     }
+
+    #[getter]
+    fn mania_keys(&self) -> Option<u32> {
+        (self.inner.mode == GameMode::Mania).then(|| mania::total_columns(&self.inner) as u32)
+    }
+
+    #[getter]
+    fn mania_columns(&self) -> Option<Vec<PyManiaColumn>> {
+        mania::columns(&self.inner)
+    }
+
+    #[getter]
+    fn mania_ln_ratio(&self) -> Option<f64> {
+        if self.inner.mode != GameMode::Mania {
+            return None;
+        }
+
+        if self.inner.hit_objects.is_empty() {
+            return Some(0.0);
+        }
+
+        Some(self.n_holds() as f64 / self.inner.hit_objects.len() as f64)
+    }
+
+    #[getter]
+    fn hit_objects(&self) -> Vec<PyHitObject> {
+        let mut bufs = CurveBuffers::default();
+
+        self.inner
+            .hit_objects
+            .iter()
+            .map(|h| PyHitObject::new(h, &self.inner, &mut bufs))
+            .collect()
+    }
+
+    #[getter]
+    fn timing_points(&self) -> Vec<PyTimingPoint> {
+        self.inner
+            .timing_points
+            .iter()
+            .map(|point| PyTimingPoint::new(point, &self.time_signatures))
+            .collect()
+    }
+
+    #[getter]
+    fn difficulty_points(&self) -> Vec<PyDifficultyPoint> {
+        self.inner
+            .difficulty_points
+            .iter()
+            .map(PyDifficultyPoint::from)
+            .collect()
+    }
+
+    #[getter]
+    fn effect_points(&self) -> Vec<PyEffectPoint> {
+        self.inner
+            .effect_points
+            .iter()
+            .map(PyEffectPoint::from)
+            .collect()
+    }
+
+    #[getter]
+    fn md5(&self) -> Option<String> {
+        self.md5.clone()
+    }
+
+    #[getter]
+    fn parse_warnings(&self) -> Vec<PyParseWarning> {
+        self.parse_warnings.clone()
+    }
+
+    #[getter]
+    fn metadata(&self) -> PyBeatmapMetadata {
+        self.metadata.clone()
+    }
+
+    #[getter]
+    fn breaks(&self) -> Vec<PyBreakPeriod> {
+        self.inner.breaks.iter().map(PyBreakPeriod::from).collect()
+    }
+
+    #[getter]
+    fn total_break_time(&self) -> f64 {
+        self.inner.total_break_time()
+    }
+
+    #[getter]
+    fn play_length(&self) -> f64 {
+        let Some(first) = self.inner.hit_objects.first() else {
+            return 0.0;
+        };
+
+        let mut bufs = CurveBuffers::default();
+
+        let last_end_time = self
+            .inner
+            .hit_objects
+            .iter()
+            .map(|h| hit_object::end_time(h, &self.inner, &mut bufs))
+            .fold(first.start_time, f64::max);
+
+        last_end_time - first.start_time
+    }
+
+    #[getter]
+    fn drain_time(&self) -> f64 {
+        (self.play_length() - self.total_break_time()).max(0.0)
+    }
+}
+
+fn convert_map(
+    map: &mut Beatmap,
+    mode: PyGameMode,
+    mods: Option<&Py<PyAny>>,
+    py: Python<'_>,
+) -> PyResult<()> {
+    let mods = match PyGameMods::extract(mods, mode.into(), py) {
+        Ok(PyGameMods::Lazer(mods)) => mods.into(),
+        Ok(PyGameMods::Intermode(mods)) => mods.into(),
+        Ok(PyGameMods::Legacy(mods)) => mods.into(),
+        Err(err) => return Err(err),
+    };
+
+    let mode = GameMode::from(mode);
+
+    if let Err(err) = map.convert_mut(mode, &mods) {
+        return Err(ConvertError::new_err(err.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Reads the bytes of a `.osu` file from one of the source kwargs and passes
+/// them to `f`.
+fn read_source<T>(
+    src: Option<(&str, Bound<'_, PyAny>)>,
+    limits: &ParseLimits,
+    f: impl FnOnce(&[u8]) -> Result<T, DecodeError>,
+) -> PyResult<Option<Result<T, DecodeError>>> {
+    let res = match src {
+        Some(("path", value)) => {
+            let path: PathBuf = extract!(path = value as "str or os.PathLike");
+
+            decode::read_path(&path, limits).and_then(|bytes| f(&bytes))
+        }
+        Some(("content", value)) => {
+            if let Ok(content) = value.extract::<&str>() {
+                f(content.as_bytes())
+            } else {
+                let bytes = BufferBytes::extract(&value).ok_or_else(|| {
+                    PyTypeError::new_err("kwarg 'content': must be str or bytes-like")
+                })?;
+
+                f(bytes.as_bytes())
+            }
+        }
+        Some(("file", value)) => match buffer::read_file(&value, limits.max_file_size)? {
+            Ok(bytes) => f(&bytes),
+            Err(err) => Err(err.into()),
+        },
+        Some((_, value)) => {
+            let bytes = BufferBytes::extract(&value)
+                .ok_or_else(|| PyTypeError::new_err("kwarg 'bytes': must be bytes-like"))?;
+
+            f(bytes.as_bytes())
+        }
+        None => return Ok(None),
+    };
+
+    Ok(Some(res))
+}
+
+impl PyBeatmap {
+    /// The clock rate of the given mods, unless a custom clock rate is
+    /// specified.
+    fn clock_rate(
+        &self,
+        mods: Option<&Py<PyAny>>,
+        clock_rate: Option<f64>,
+        py: Python<'_>,
+    ) -> PyResult<f64> {
+        let mut builder = BeatmapAttributesBuilder::new();
+
+        match PyGameMods::extract(mods, self.inner.mode, py) {
+            Ok(PyGameMods::Lazer(mods)) => builder.mods(mods),
+            Ok(PyGameMods::Intermode(ref mods)) => builder.mods(mods),
+            Ok(PyGameMods::Legacy(mods)) => builder.mods(mods),
+            Err(err) => return Err(err),
+        };
+
+        if let Some(clock_rate) = clock_rate {
+            if !(clock_rate.is_finite() && clock_rate > 0.0) {
+                return Err(ArgsError::new_err("clock rate must be a positive number"));
+            }
+
+            builder.clock_rate(clock_rate);
+        }
+
+        Ok(builder.build().clock_rate())
+    }
+
+    /// Maps each path to its beatmap or, if parsing failed, its `ParseError`.
+    fn batch_dict<'py>(
+        py: Python<'py>,
+        paths: &[PathBuf],
+        results: Vec<Result<(DecodedBeatmap, String), DecodeError>>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let maps = PyDict::new(py);
+
+        for (path, res) in paths.iter().zip(results) {
+            let key = path.to_string_lossy();
+
+            match res {
+                Ok((decoded, md5)) => maps.set_item(key, Self::decoded(decoded, md5))?,
+                Err(err) => maps.set_item(key, parse_error(py, &err).into_value(py))?,
+            }
+        }
+
+        Ok(maps)
+    }
+
+    fn encode(&self) -> PyResult<String> {
+        // The taiko difficulty and performance calculation depends on whether
+        // the map is a convert but the file format cannot express that
+        if self.inner.is_convert && self.inner.mode == GameMode::Taiko {
+            return Err(ConvertError::new_err(
+                "Taiko converts cannot be encoded without changing their difficulty",
+            ));
+        }
+
+        Ok(encode::encode(self))
+    }
+
+    /// A clone that is about to be modified and thus no longer matches the
+    /// MD5 digest of the original file.
+    fn transformed(&self) -> Self {
+        Self {
+            md5: None,
+            ..self.clone()
+        }
+    }
+
+    fn emit_parse_warnings(&self, py: Python<'_>) -> PyResult<()> {
+        let category = py.get_type::<PyUserWarning>();
+
+        for warning in self.parse_warnings.iter() {
+            let msg = CString::new(warning.message().replace('\0', ""))?;
+            PyErr::warn(py, &category, &msg, 2)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads and decodes a `.osu` file alongside its MD5 digest.
+fn decode_path(path: &Path) -> Result<(DecodedBeatmap, String), DecodeError> {
+    let options = ParseOptions::default();
+    let bytes = decode::read_path(path, &options.limits)?;
+    let decoded = decode::from_bytes(&bytes, &options)?;
+
+    Ok((decoded, md5::hex_digest(&bytes)))
+}
+
+fn unpickle_error(py: Python<'_>, err: UnpickleError) -> PyErr {
+    let msg = match err {
+        UnpickleError::Invalid => "Failed to unpickle beatmap: invalid state".to_owned(),
+        UnpickleError::UnsupportedVersion(version) => {
+            format!("Failed to unpickle beatmap: unsupported format version {version}")
+        }
+    };
+
+    parse_error_from_msg(py, msg)
+}
+
+fn parse_error(py: Python<'_>, err: &(dyn StdError + 'static)) -> PyErr {
+    parse_error_with(py, err, "Failed to parse beatmap")
+}
+
+fn parse_osz_error(py: Python<'_>, err: &(dyn StdError + 'static)) -> PyErr {
+    parse_error_with(py, err, "Failed to read .osz archive")
+}
+
+/// Creates a [`ParseError`] that lists the error and all of its sources.
+fn parse_error_with(py: Python<'_>, err: &(dyn StdError + 'static), msg: &str) -> PyErr {
+    let mut causes = vec![err.to_string()];
+    let mut src = err;
+
+    while let Some(next) = src.source() {
+        causes.push(next.to_string());
+        src = next;
+    }
+
+    let mut content = msg.to_owned();
+
+    for cause in causes.iter() {
+        let _ = write!(content, "\n  - caused by: {cause}");
+    }
+
+    let py_err = ParseError::new_err(content);
+
+    let location = err
+        .downcast_ref::<DecodeError>()
+        .and_then(DecodeError::location);
+
+    match set_parse_error_attrs(py_err.value(py), causes, location) {
+        Ok(()) => py_err,
+        Err(err) => err,
+    }
+}
+
+fn set_parse_error_attrs(
+    err: &Bound<'_, PyBaseException>,
+    causes: Vec<String>,
+    location: Option<&LineLocation>,
+) -> PyResult<()> {
+    err.setattr(intern!(err.py(), "causes"), causes)?;
+
+    if let Some(location) = location {
+        let section = decode::section_name(location.section);
+        err.setattr(intern!(err.py(), "section"), section)?;
+        err.setattr(intern!(err.py(), "line_number"), location.line_number)?;
+        err.setattr(intern!(err.py(), "line"), &location.line)?;
+    }
+
+    Ok(())
 }
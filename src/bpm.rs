@@ -0,0 +1,122 @@
+//! BPM statistics of a beatmap.
+
+use pyo3::pyclass;
+use rosu_pp::{
+    model::{control_point::TimingPoint, hit_object::HitObjectKind},
+    Beatmap,
+};
+
+define_class! {
+    #[pyclass(name = "BpmStats", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyBpmStats {
+        pub min: f64!,
+        pub max: f64!,
+        pub most_common: f64!,
+        pub clock_rate: f64!,
+    }
+}
+
+/// Computes the minimum, maximum, and most common BPM, adjusted by the clock
+/// rate.
+///
+/// Timing points after the last hit object are ignored.
+pub fn stats(map: &Beatmap, clock_rate: f64) -> PyBpmStats {
+    let last_time = last_time(map);
+    let points = relevant_points(map, last_time);
+
+    let (min, max) = points
+        .iter()
+        .map(TimingPoint::bpm)
+        .fold(None, |min_max, bpm| match min_max {
+            Some((min, max)) => Some((f64::min(min, bpm), f64::max(max, bpm))),
+            None => Some((bpm, bpm)),
+        })
+        .unwrap_or((0.0, 0.0));
+
+    let most_common = most_common(points, last_time);
+
+    PyBpmStats {
+        min: min * clock_rate,
+        max: max * clock_rate,
+        most_common: most_common * clock_rate,
+        clock_rate,
+    }
+}
+
+/// Start times and BPM of all sections with a different BPM than the
+/// previous section, adjusted by the clock rate.
+pub fn timeline(map: &Beatmap, clock_rate: f64) -> Vec<(f64, f64)> {
+    let mut timeline: Vec<(f64, f64)> = Vec::new();
+
+    for point in relevant_points(map, last_time(map)) {
+        let bpm = point.bpm() * clock_rate;
+
+        match timeline.last() {
+            Some((_, prev)) if *prev == bpm => {}
+            _ => timeline.push((point.time / clock_rate, bpm)),
+        }
+    }
+
+    timeline
+}
+
+/// The BPM with the longest total duration.
+///
+/// Unlike [`Beatmap::bpm`], the BPM is not rounded so that it's always
+/// within the minimum and maximum.
+fn most_common(points: &[TimingPoint], last_time: Option<f64>) -> f64 {
+    let Some(first) = points.first() else {
+        return 0.0;
+    };
+
+    let end_time = last_time.unwrap_or(points[points.len() - 1].time);
+
+    // Beat lengths are rounded to 3 decimals for grouping
+    let mut durations: Vec<(f64, f64, &TimingPoint)> = Vec::new();
+
+    for (i, point) in points.iter().enumerate() {
+        // Same as osu!stable, the first section starts at 0
+        let start = if i == 0 { 0.0 } else { point.time };
+        let end = points.get(i + 1).map_or(end_time, |next| next.time);
+        let key = (1000.0 * point.beat_len).round_ties_even() / 1000.0;
+
+        match durations.iter_mut().find(|(k, ..)| *k == key) {
+            Some((_, duration, _)) => *duration += end - start,
+            None => durations.push((key, end - start, point)),
+        }
+    }
+
+    durations
+        .iter()
+        .max_by(|(_, a, _), (_, b, _)| a.total_cmp(b))
+        .map_or(first, |(.., point)| point)
+        .bpm()
+}
+
+/// End time of the last hit object; slider ends are not considered, same as
+/// in rosu-pp.
+fn last_time(map: &Beatmap) -> Option<f64> {
+    map.hit_objects.last().map(|h| match h.kind {
+        HitObjectKind::Circle | HitObjectKind::Slider(_) => h.start_time,
+        HitObjectKind::Spinner(ref spinner) => h.start_time + spinner.duration,
+        HitObjectKind::Hold(ref hold) => h.start_time + hold.duration,
+    })
+}
+
+/// Timing points up to the given time.
+///
+/// The first timing point is always included.
+fn relevant_points(map: &Beatmap, last_time: Option<f64>) -> &[TimingPoint] {
+    let Some(last_time) = last_time else {
+        return &map.timing_points;
+    };
+
+    let len = map
+        .timing_points
+        .partition_point(|point| point.time <= last_time)
+        .max(1)
+        .min(map.timing_points.len());
+
+    &map.timing_points[..len]
+}
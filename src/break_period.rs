@@ -0,0 +1,22 @@
+use pyo3::pyclass;
+use rosu_pp::model::beatmap::BreakPeriod;
+
+define_class! {
+    #[pyclass(name = "BreakPeriod", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyBreakPeriod {
+        pub start_time: f64!,
+        pub end_time: f64!,
+        pub duration: f64!,
+    }
+}
+
+impl From<&BreakPeriod> for PyBreakPeriod {
+    fn from(period: &BreakPeriod) -> Self {
+        Self {
+            start_time: period.start_time,
+            end_time: period.end_time,
+            duration: period.duration(),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::slice;
+
+use pyo3::{
+    buffer::PyUntypedBuffer,
+    exceptions::PyTypeError,
+    intern,
+    types::{PyAnyMethods, PyBytes, PyBytesMethods, PyString, PyStringMethods},
+    Bound, PyAny, PyResult,
+};
+
+use crate::decode::LimitExceeded;
+
+/// Bytes of a python object that supports the buffer protocol.
+///
+/// Contiguous buffers are borrowed, others are copied.
+pub struct BufferBytes {
+    // Keeps the buffer alive while it's being borrowed
+    buf: PyUntypedBuffer,
+    copied: Option<Vec<u8>>,
+}
+
+impl BufferBytes {
+    pub fn extract(value: &Bound<'_, PyAny>) -> Option<Self> {
+        let buf = PyUntypedBuffer::get(value).ok()?;
+
+        let copied = if buf.is_c_contiguous() {
+            None
+        } else {
+            let bytes = value.py().import("builtins").ok()?.getattr("bytes").ok()?;
+            let copied = bytes.call1((value,)).ok()?;
+
+            Some(copied.cast_into::<PyBytes>().ok()?.as_bytes().to_vec())
+        };
+
+        Some(Self { buf, copied })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        if let Some(ref copied) = self.copied {
+            return copied;
+        }
+
+        let len = self.buf.len_bytes();
+
+        if len == 0 {
+            return &[];
+        }
+
+        // SAFETY: The buffer is C-contiguous, non-empty, and kept alive by
+        // `self`. Python code cannot mutate it while the GIL is being held
+        // during parsing.
+        unsafe { slice::from_raw_parts(self.buf.buf_ptr().cast::<u8>(), len) }
+    }
+}
+
+/// Reads all content of a python file object in chunks.
+///
+/// Both binary and text files are supported.
+pub fn read_file(
+    file: &Bound<'_, PyAny>,
+    max_size: Option<u64>,
+) -> PyResult<Result<Vec<u8>, LimitExceeded>> {
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    let read = match file.getattr(intern!(file.py(), "read")) {
+        Ok(read) if read.is_callable() => read,
+        _ => {
+            return Err(PyTypeError::new_err(
+                "kwarg 'file': must be a readable file object",
+            ))
+        }
+    };
+    let mut content = Vec::new();
+
+    loop {
+        let chunk = read.call1((CHUNK_SIZE,))?;
+
+        if let Ok(chunk) = chunk.cast::<PyString>() {
+            content.extend_from_slice(chunk.to_str()?.as_bytes());
+        } else if let Some(chunk) = BufferBytes::extract(&chunk) {
+            content.extend_from_slice(chunk.as_bytes());
+        } else {
+            return Err(PyTypeError::new_err(
+                "kwarg 'file': `read` must return bytes or str",
+            ));
+        }
+
+        if chunk.len()? == 0 {
+            return Ok(Ok(content));
+        }
+
+        if let Some(limit) = max_size {
+            let size = content.len() as u64;
+
+            if size > limit {
+                return Ok(Err(LimitExceeded::FileSize { size, limit }));
+            }
+        }
+    }
+}
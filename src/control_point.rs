@@ -0,0 +1,72 @@
+use pyo3::pyclass;
+use rosu_pp::model::control_point::{DifficultyPoint, EffectPoint, TimingPoint};
+
+use crate::decode::DEFAULT_METER;
+
+define_class! {
+    #[pyclass(name = "TimingPoint", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyTimingPoint {
+        pub time: f64!,
+        pub beat_len: f64!,
+        pub bpm: f64!,
+        pub meter: i32!,
+    }
+}
+
+impl PyTimingPoint {
+    pub(crate) fn new(point: &TimingPoint, time_signatures: &[(f64, i32)]) -> Self {
+        let meter = time_signatures
+            .binary_search_by(|probe| probe.0.total_cmp(&point.time))
+            .map_or(DEFAULT_METER, |i| time_signatures[i].1);
+
+        Self {
+            time: point.time,
+            beat_len: point.beat_len,
+            bpm: point.bpm(),
+            meter,
+        }
+    }
+}
+
+define_class! {
+    #[pyclass(name = "DifficultyPoint", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyDifficultyPoint {
+        pub time: f64!,
+        pub slider_velocity: f64!,
+        pub bpm_multiplier: f64!,
+        pub generate_ticks: bool!,
+    }
+}
+
+impl From<&DifficultyPoint> for PyDifficultyPoint {
+    fn from(point: &DifficultyPoint) -> Self {
+        Self {
+            time: point.time,
+            slider_velocity: point.slider_velocity,
+            bpm_multiplier: point.bpm_multiplier,
+            generate_ticks: point.generate_ticks,
+        }
+    }
+}
+
+define_class! {
+    #[pyclass(name = "EffectPoint", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyEffectPoint {
+        pub time: f64!,
+        pub kiai: bool!,
+        pub scroll_speed: f64!,
+    }
+}
+
+impl From<&EffectPoint> for PyEffectPoint {
+    fn from(point: &EffectPoint) -> Self {
+        Self {
+            time: point.time,
+            kiai: point.kiai,
+            scroll_speed: point.scroll_speed,
+        }
+    }
+}
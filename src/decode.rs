@@ -0,0 +1,727 @@
+use std::{
+    borrow::Cow,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use rosu_map::{
+    section::{general::General, metadata::Metadata, Section},
+    DecodeBeatmap, DecodeState, LATEST_FORMAT_VERSION,
+};
+use rosu_pp::{
+    model::beatmap::{BeatmapState, ParseBeatmapError},
+    Beatmap,
+};
+
+use crate::metadata::PyBeatmapMetadata;
+
+/// A [`Beatmap`] alongside content of the `.osu` file that is not relevant
+/// for rosu-pp and thus not stored in the [`Beatmap`] itself.
+pub struct DecodedBeatmap {
+    pub map: Beatmap,
+    /// Time and meter of uninherited timing points.
+    pub time_signatures: Vec<(f64, i32)>,
+    pub metadata: PyBeatmapMetadata,
+    /// Invalid lines that were skipped.
+    pub warnings: Vec<ParseWarning>,
+}
+
+pub struct DecodedBeatmapState {
+    map: BeatmapState,
+    time_signatures: TimeSignatures,
+    metadata: Metadata,
+    general: General,
+    warnings: Vec<ParseWarning>,
+}
+
+impl DecodeState for DecodedBeatmapState {
+    fn create(version: i32) -> Self {
+        Self {
+            map: BeatmapState::create(version),
+            time_signatures: TimeSignatures::default(),
+            metadata: Metadata::default(),
+            general: General::default(),
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl From<DecodedBeatmapState> for DecodedBeatmap {
+    fn from(state: DecodedBeatmapState) -> Self {
+        Self {
+            map: state.map.into(),
+            time_signatures: state.time_signatures.finish(),
+            metadata: PyBeatmapMetadata::new(state.metadata, state.general),
+            warnings: state.warnings,
+        }
+    }
+}
+
+impl DecodeBeatmap for DecodedBeatmap {
+    type Error = ParseBeatmapError;
+    type State = DecodedBeatmapState;
+
+    fn parse_general(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        // Fields that are irrelevant for rosu-pp are parsed leniently
+        let _ = General::parse_general(&mut state.general, line);
+
+        Beatmap::parse_general(&mut state.map, line)
+    }
+
+    fn parse_editor(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_editor(&mut state.map, line)
+    }
+
+    fn parse_metadata(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        // Metadata is irrelevant for rosu-pp so it's parsed leniently
+        let _ = Metadata::parse_metadata(&mut state.metadata, line);
+
+        Beatmap::parse_metadata(&mut state.map, line)
+    }
+
+    fn parse_difficulty(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_difficulty(&mut state.map, line)
+    }
+
+    fn parse_events(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_events(&mut state.map, line)
+    }
+
+    fn parse_timing_points(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_timing_points(&mut state.map, line)?;
+
+        // The line was valid so we can be lenient here
+        let mut split = line.split("//").next().unwrap_or(line).split(',');
+        let time = split.next().and_then(|s| s.trim().parse::<f64>().ok());
+        let meter = split.nth(1).and_then(|s| s.trim().parse::<i32>().ok());
+        let timing_change = split
+            .nth(3)
+            .is_none_or(|s| matches!(s.chars().next(), Some('1')));
+
+        if let Some(time) = time {
+            let meter = meter.unwrap_or(DEFAULT_METER);
+            state.time_signatures.add(time, meter, timing_change);
+        }
+
+        Ok(())
+    }
+
+    fn parse_colors(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_colors(&mut state.map, line)
+    }
+
+    fn parse_hit_objects(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_hit_objects(&mut state.map, line)
+    }
+
+    fn parse_variables(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_variables(&mut state.map, line)
+    }
+
+    fn parse_catch_the_beat(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_catch_the_beat(&mut state.map, line)
+    }
+
+    fn parse_mania(state: &mut Self::State, line: &str) -> Result<(), Self::Error> {
+        Beatmap::parse_mania(&mut state.map, line)
+    }
+}
+
+/// Meter of timing points that don't specify one.
+pub const DEFAULT_METER: i32 = 4;
+
+/// Collects the meter of uninherited timing points the same way rosu-pp
+/// collects the timing points themselves so that both always stem from the
+/// same line.
+///
+/// Consecutive lines at the same time are pending until a line with a
+/// different time comes up. Of those, the first uninherited one is kept.
+/// When flushed, a pending meter replaces any previous one at that time.
+#[derive(Default)]
+struct TimeSignatures {
+    points: Vec<(f64, i32)>,
+    pending_time: f64,
+    pending: Option<(f64, i32)>,
+}
+
+impl TimeSignatures {
+    fn add(&mut self, time: f64, meter: i32, timing_change: bool) {
+        if (time - self.pending_time).abs() >= f64::EPSILON {
+            self.flush();
+        }
+
+        if timing_change && self.pending.is_none() {
+            self.pending = Some((time, meter));
+        }
+
+        self.pending_time = time;
+    }
+
+    fn flush(&mut self) {
+        let Some((time, meter)) = self.pending.take() else {
+            return;
+        };
+
+        match self
+            .points
+            .binary_search_by(|probe| probe.0.total_cmp(&time))
+        {
+            Ok(i) => self.points[i].1 = meter,
+            Err(i) => self.points.insert(i, (time, meter)),
+        }
+    }
+
+    fn finish(mut self) -> Vec<(f64, i32)> {
+        self.flush();
+
+        self.points
+    }
+}
+
+/// Limits to abort parsing of untrusted beatmaps early.
+#[derive(Default)]
+pub struct ParseLimits {
+    /// Maximum size of the `.osu` file in bytes.
+    pub max_file_size: Option<u64>,
+    pub max_hit_objects: Option<usize>,
+    /// Maximum pixel length of sliders as specified in the `.osu` file.
+    pub max_slider_length: Option<f64>,
+    pub max_slider_repeats: Option<usize>,
+    /// Maximum amount of timing points, including inherited ones.
+    pub max_timing_points: Option<usize>,
+}
+
+/// Options on how to decode a `.osu` file.
+#[derive(Default)]
+pub struct ParseOptions {
+    pub limits: ParseLimits,
+    /// Whether invalid lines abort decoding instead of being skipped.
+    pub strict: bool,
+}
+
+/// A [`ParseLimits`] limit that was exceeded.
+#[derive(Debug)]
+pub enum LimitExceeded {
+    FileSize { size: u64, limit: u64 },
+    HitObjects { limit: usize },
+    SliderLength { length: f64, limit: f64 },
+    SliderRepeats { repeats: usize, limit: usize },
+    TimingPoints { limit: usize },
+}
+
+impl StdError for LimitExceeded {}
+
+impl Display for LimitExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::FileSize { size, limit } => write!(
+                f,
+                "file size of {size} bytes exceeds the limit of {limit} bytes"
+            ),
+            Self::HitObjects { limit } => {
+                write!(f, "amount of hit objects exceeds the limit of {limit}")
+            }
+            Self::SliderLength { length, limit } => {
+                write!(f, "slider length of {length} exceeds the limit of {limit}")
+            }
+            Self::SliderRepeats { repeats, limit } => {
+                write!(
+                    f,
+                    "slider repeat count of {repeats} exceeds the limit of {limit}"
+                )
+            }
+            Self::TimingPoints { limit } => {
+                write!(f, "amount of timing points exceeds the limit of {limit}")
+            }
+        }
+    }
+}
+
+/// Position of a line within a `.osu` file.
+#[derive(Debug)]
+pub struct LineLocation {
+    pub section: Section,
+    /// One-based line number.
+    pub line_number: usize,
+    pub line: String,
+}
+
+/// An invalid line that was skipped while decoding.
+#[derive(Debug)]
+pub struct ParseWarning {
+    pub location: LineLocation,
+    /// The error and all of its causes, separated by `": "`.
+    pub reason: String,
+}
+
+impl ParseWarning {
+    fn new(location: LineLocation, err: &(dyn StdError + 'static)) -> Self {
+        let mut reason = err.to_string();
+        let mut src = err;
+
+        while let Some(next) = src.source() {
+            reason.push_str(": ");
+            reason.push_str(&next.to_string());
+            src = next;
+        }
+
+        Self { location, reason }
+    }
+}
+
+#[derive(Debug)]
+pub enum LineErrorKind {
+    Parse(ParseBeatmapError),
+    Limit(LimitExceeded),
+}
+
+/// Parsing failed on a specific line.
+#[derive(Debug)]
+pub struct LineError {
+    pub location: LineLocation,
+    pub kind: LineErrorKind,
+}
+
+impl StdError for LineError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self.kind {
+            LineErrorKind::Parse(ref err) => Some(err),
+            LineErrorKind::Limit(ref err) => Some(err),
+        }
+    }
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let LineLocation {
+            section,
+            line_number,
+            ..
+        } = self.location;
+
+        write!(
+            f,
+            "failed on line {line_number} in section [{}]",
+            section_name(section)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum DecodeError {
+    Io(io::Error),
+    Limit(LimitExceeded),
+    Line(Box<LineError>),
+}
+
+impl DecodeError {
+    /// The line on which decoding failed, if any.
+    pub const fn location(&self) -> Option<&LineLocation> {
+        match self {
+            Self::Io(_) | Self::Limit(_) => None,
+            Self::Line(err) => Some(&err.location),
+        }
+    }
+}
+
+impl From<io::Error> for DecodeError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<LimitExceeded> for DecodeError {
+    fn from(err: LimitExceeded) -> Self {
+        Self::Limit(err)
+    }
+}
+
+impl From<LineError> for DecodeError {
+    fn from(err: LineError) -> Self {
+        Self::Line(Box::new(err))
+    }
+}
+
+impl StdError for DecodeError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Io(err) => err.source(),
+            Self::Limit(err) => err.source(),
+            Self::Line(err) => err.source(),
+        }
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Io(err) => Display::fmt(err, f),
+            Self::Limit(err) => Display::fmt(err, f),
+            Self::Line(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+/// The name of a section as it appears in `.osu` files.
+pub const fn section_name(section: Section) -> &'static str {
+    match section {
+        Section::General => "General",
+        Section::Editor => "Editor",
+        Section::Metadata => "Metadata",
+        Section::Difficulty => "Difficulty",
+        Section::Events => "Events",
+        Section::TimingPoints => "TimingPoints",
+        Section::Colors => "Colours",
+        Section::HitObjects => "HitObjects",
+        Section::Variables => "Variables",
+        Section::CatchTheBeat => "CatchTheBeat",
+        Section::Mania => "Mania",
+    }
+}
+
+pub fn from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<DecodedBeatmap, DecodeError> {
+    check_file_size(bytes, &options.limits)?;
+
+    decode(bytes, options)
+}
+
+/// Reads the file at the given path while respecting the file size limit.
+pub fn read_path(path: &Path, limits: &ParseLimits) -> Result<Vec<u8>, DecodeError> {
+    let file = File::open(path)?;
+
+    let mut bytes = Vec::new();
+
+    if let Some(limit) = limits.max_file_size {
+        let size = file.metadata()?.len();
+
+        if size > limit {
+            return Err(LimitExceeded::FileSize { size, limit }.into());
+        }
+
+        // The file might have grown in the meanwhile
+        let size = file.take(limit + 1).read_to_end(&mut bytes)? as u64;
+
+        if size > limit {
+            return Err(LimitExceeded::FileSize { size, limit }.into());
+        }
+    } else {
+        (&file).read_to_end(&mut bytes)?;
+    }
+
+    Ok(bytes)
+}
+
+pub fn check_file_size(bytes: &[u8], limits: &ParseLimits) -> Result<(), LimitExceeded> {
+    match limits.max_file_size {
+        Some(limit) if bytes.len() as u64 > limit => Err(LimitExceeded::FileSize {
+            size: bytes.len() as u64,
+            limit,
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn decode(bytes: &[u8], options: &ParseOptions) -> Result<DecodedBeatmap, DecodeError> {
+    decode_state(bytes, options).map(DecodedBeatmap::from)
+}
+
+/// Same as [`DecodeBeatmap::decode`] but checks the given options along the
+/// way.
+fn decode_state(bytes: &[u8], options: &ParseOptions) -> Result<DecodedBeatmapState, DecodeError> {
+    let ParseOptions { ref limits, strict } = *options;
+
+    let content = decode_text(bytes);
+    let mut lines = numbered_lines(&content);
+    let (version, section) = decode_header(&mut lines);
+    let mut state = DecodedBeatmapState::create(version);
+
+    let Some(mut section) = section else {
+        return Ok(state);
+    };
+
+    let mut n_hit_objects = 0;
+    let mut n_timing_points = 0;
+
+    for (line_number, line) in lines {
+        if DecodedBeatmap::should_skip_line(line) {
+            continue;
+        }
+
+        if let Some(next) = Section::try_from_line(line) {
+            section = next;
+
+            continue;
+        }
+
+        let location = || LineLocation {
+            section,
+            line_number,
+            line: line.to_owned(),
+        };
+
+        let line_error = |kind| LineError {
+            location: location(),
+            kind,
+        };
+
+        let limit_exceeded = |err| line_error(LineErrorKind::Limit(err));
+
+        let res = match section {
+            Section::General => DecodedBeatmap::parse_general(&mut state, line),
+            Section::Editor => DecodedBeatmap::parse_editor(&mut state, line),
+            Section::Metadata => DecodedBeatmap::parse_metadata(&mut state, line),
+            Section::Difficulty => DecodedBeatmap::parse_difficulty(&mut state, line),
+            // Storyboard lines are valid but rosu-pp rejects them
+            Section::Events if !is_break_event(line) => continue,
+            Section::Events => DecodedBeatmap::parse_events(&mut state, line),
+            Section::TimingPoints => {
+                let res = DecodedBeatmap::parse_timing_points(&mut state, line);
+
+                if res.is_ok() {
+                    n_timing_points += 1;
+
+                    match limits.max_timing_points {
+                        Some(limit) if n_timing_points > limit => {
+                            return Err(limit_exceeded(LimitExceeded::TimingPoints { limit }).into())
+                        }
+                        _ => {}
+                    }
+                }
+
+                res
+            }
+            Section::Colors => DecodedBeatmap::parse_colors(&mut state, line),
+            Section::HitObjects => {
+                check_slider(line, limits).map_err(limit_exceeded)?;
+                let res = DecodedBeatmap::parse_hit_objects(&mut state, line);
+
+                if res.is_ok() {
+                    n_hit_objects += 1;
+
+                    match limits.max_hit_objects {
+                        Some(limit) if n_hit_objects > limit => {
+                            return Err(limit_exceeded(LimitExceeded::HitObjects { limit }).into())
+                        }
+                        _ => {}
+                    }
+                }
+
+                res
+            }
+            Section::Variables => DecodedBeatmap::parse_variables(&mut state, line),
+            Section::CatchTheBeat => DecodedBeatmap::parse_catch_the_beat(&mut state, line),
+            Section::Mania => DecodedBeatmap::parse_mania(&mut state, line),
+        };
+
+        // Same as rosu-map, invalid lines are skipped unless decoding is strict
+        match res {
+            Ok(()) => {}
+            Err(err) if strict => return Err(line_error(LineErrorKind::Parse(err)).into()),
+            Err(err) => state.warnings.push(ParseWarning::new(location(), &err)),
+        }
+    }
+
+    Ok(state)
+}
+
+/// Whether an `[Events]` line describes a break.
+///
+/// Breaks are the only events relevant for gameplay; all other lines such as
+/// backgrounds, videos, or storyboard elements and their commands are ignored.
+fn is_break_event(line: &str) -> bool {
+    matches!(line.split(',').next(), Some("2" | "Break"))
+}
+
+const VERSION_PREFIX: &str = "osu file format v";
+
+/// Lines of a `.osu` file alongside their one-based line number.
+pub fn numbered_lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end()))
+}
+
+/// Reads the format version and the first section of a `.osu` file.
+///
+/// The section is `None` if there are no sections.
+pub fn decode_header<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> (i32, Option<Section>) {
+    let mut version = None;
+    let mut curr_line = None;
+
+    for (line_number, line) in lines.by_ref() {
+        if line.is_empty() {
+            continue;
+        }
+
+        version = line
+            .strip_prefix(VERSION_PREFIX)
+            .and_then(|_| line.rsplit('v').next())
+            .and_then(|version| version.trim().parse::<i32>().ok());
+
+        if version.is_none() {
+            curr_line = Some((line_number, line));
+        }
+
+        break;
+    }
+
+    let section = curr_line
+        .into_iter()
+        .chain(lines)
+        .find_map(|(_, line)| Section::try_from_line(line));
+
+    (version.unwrap_or(LATEST_FORMAT_VERSION), section)
+}
+
+/// Decodes the content of a `.osu` file based on its byte order mark.
+///
+/// Invalid characters are replaced with U+FFFD.
+pub fn decode_text(bytes: &[u8]) -> Cow<'_, str> {
+    fn decode_utf16(bytes: &[u8], f: fn([u8; 2]) -> u16) -> Cow<'static, str> {
+        let units = bytes.chunks_exact(2).map(|chunk| f([chunk[0], chunk[1]]));
+
+        char::decode_utf16(units)
+            .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect::<String>()
+            .into()
+    }
+
+    match bytes {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8_lossy(rest),
+        [0xFF, 0xFE, rest @ ..] => decode_utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => decode_utf16(rest, u16::from_be_bytes),
+        _ => String::from_utf8_lossy(bytes),
+    }
+}
+
+/// Checks slider limits before the hit object line is parsed.
+fn check_slider(line: &str, limits: &ParseLimits) -> Result<(), LimitExceeded> {
+    /// Hit object type flags of circles and sliders.
+    const CIRCLE: i32 = 1;
+    const SLIDER: i32 = 2;
+
+    if limits.max_slider_length.is_none() && limits.max_slider_repeats.is_none() {
+        return Ok(());
+    }
+
+    let mut split = line.split("//").next().unwrap_or(line).split(',');
+
+    let Some(kind) = split.nth(3).and_then(|s| s.trim().parse::<i32>().ok()) else {
+        return Ok(());
+    };
+
+    if kind & CIRCLE != 0 || kind & SLIDER == 0 {
+        return Ok(());
+    }
+
+    // Skipping hitsound and path
+    let mut split = split.skip(2);
+
+    let repeats = split.next().and_then(|s| s.trim().parse::<i32>().ok());
+    let length = split.next().and_then(|s| s.trim().parse::<f64>().ok());
+
+    match (repeats, limits.max_slider_repeats) {
+        (Some(slides), Some(limit)) if (slides - 1).max(0) as usize > limit => {
+            return Err(LimitExceeded::SliderRepeats {
+                repeats: (slides - 1) as usize,
+                limit,
+            });
+        }
+        _ => {}
+    }
+
+    match (length, limits.max_slider_length) {
+        (Some(length), Some(limit)) if length > limit => {
+            Err(LimitExceeded::SliderLength { length, limit })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = include_str!("../tests/fixtures/test_map.osu");
+
+    fn decode_default(bytes: &[u8]) -> DecodedBeatmap {
+        decode(bytes, &ParseOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn matches_rosu_map() {
+        let expected = rosu_map::from_str::<DecodedBeatmap>(MAP).unwrap();
+        let decoded = decode_default(MAP.as_bytes());
+
+        assert_eq!(decoded.map, expected.map);
+        assert_eq!(decoded.time_signatures, expected.time_signatures);
+        assert!(decoded.warnings.is_empty());
+    }
+
+    #[test]
+    fn utf16() {
+        let expected = decode_default(MAP.as_bytes());
+
+        let mut le = vec![0xFF, 0xFE];
+        let mut be = vec![0xFE, 0xFF];
+
+        for unit in MAP.encode_utf16() {
+            le.extend_from_slice(&unit.to_le_bytes());
+            be.extend_from_slice(&unit.to_be_bytes());
+        }
+
+        assert_eq!(decode_default(&le).map, expected.map);
+        assert_eq!(decode_default(&be).map, expected.map);
+    }
+
+    #[test]
+    fn hit_object_limit() {
+        let options = ParseOptions {
+            limits: ParseLimits {
+                max_hit_objects: Some(10),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let Err(DecodeError::Line(err)) = decode(MAP.as_bytes(), &options) else {
+            panic!("expected line error");
+        };
+
+        assert!(matches!(
+            err.kind,
+            LineErrorKind::Limit(LimitExceeded::HitObjects { limit: 10 })
+        ));
+        assert_eq!(err.location.section, Section::HitObjects);
+    }
+
+    #[test]
+    fn strict() {
+        let content = "osu file format v14\n\n[HitObjects]\n256,192,1000,1,0\n256,192,oops,1,0\n";
+
+        let lenient = decode(content.as_bytes(), &ParseOptions::default()).unwrap();
+        assert_eq!(lenient.map.hit_objects.len(), 1);
+        assert_eq!(lenient.warnings.len(), 1);
+        assert_eq!(lenient.warnings[0].location.line_number, 5);
+
+        let options = ParseOptions {
+            strict: true,
+            ..Default::default()
+        };
+
+        let Err(err) = decode(content.as_bytes(), &options) else {
+            panic!("expected error");
+        };
+
+        let location = err.location().unwrap();
+        assert_eq!(location.section, Section::HitObjects);
+        assert_eq!(location.line_number, 5);
+        assert_eq!(location.line, "256,192,oops,1,0");
+    }
+}
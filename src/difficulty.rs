@@ -2,12 +2,17 @@ use pyo3::{
     exceptions::PyTypeError,
     pyclass, pymethods,
     types::{PyAnyMethods, PyDict},
-    Bound, Py, PyAny, PyResult, Python,
+    Bound, Py, PyAny, PyRef, PyResult, Python,
+};
+use rosu_pp::{
+    any::InspectDifficulty,
+    model::{beatmap::BeatmapAttribute, mode::GameMode},
+    Difficulty, GameMods,
 };
-use rosu_pp::{model::mode::GameMode, Difficulty};
 
 use crate::{
     attributes::difficulty::PyDifficultyAttributes,
+    batch,
     beatmap::PyBeatmap,
     error::ArgsError,
     gradual::{difficulty::PyGradualDifficulty, performance::PyGradualPerformance},
@@ -98,17 +103,138 @@ impl PyDifficulty {
     }
 
     fn calculate(&self, map: &PyBeatmap, py: Python<'_>) -> PyResult<PyDifficultyAttributes> {
-        Ok(self
-            .try_as_difficulty(map.inner.mode, py)?
-            .calculate(&map.inner)
-            .into())
+        let difficulty = self.try_as_difficulty(map.inner.mode, py)?;
+
+        // Mods are extracted beforehand so the GIL can be released
+        Ok(py.detach(|| difficulty.calculate(&map.inner)).into())
+    }
+
+    #[pyo3(signature = (maps, threads=None))]
+    fn calculate_many(
+        &self,
+        maps: Vec<PyRef<'_, PyBeatmap>>,
+        threads: Option<usize>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<PyDifficultyAttributes>> {
+        let threads = batch::threads(threads)?;
+
+        // Mods depend on the mode so they're extracted once per mode
+        let mut difficulties: [Option<Difficulty>; 4] = Default::default();
+
+        for map in maps.iter() {
+            let difficulty = &mut difficulties[map.inner.mode as usize];
+
+            if difficulty.is_none() {
+                *difficulty = Some(self.try_as_difficulty(map.inner.mode, py)?);
+            }
+        }
+
+        let items: Vec<_> = maps
+            .iter()
+            .map(|map| {
+                let difficulty = difficulties[map.inner.mode as usize]
+                    .as_ref()
+                    .expect("difficulty was extracted for every mode above");
+
+                (difficulty, &map.inner)
+            })
+            .collect();
+
+        let attrs = py.detach(|| {
+            batch::map(&items, threads, |(difficulty, map)| {
+                difficulty.calculate(map)
+            })
+        });
+
+        Ok(attrs.into_iter().map(From::from).collect())
+    }
+
+    #[pyo3(signature = (map, mods, threads=None))]
+    fn calculate_mods(
+        &self,
+        map: &PyBeatmap,
+        mods: Vec<Py<PyAny>>,
+        threads: Option<usize>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<(Py<PyAny>, PyDifficultyAttributes)>> {
+        let threads = batch::threads(threads)?;
+
+        // Settings other than mods are shared across all combinations
+        let base = self.inner.clone().unwrap_or_default();
+
+        // Specs that resolve to the same mods are only calculated once
+        let mut combinations: Vec<GameMods> = Vec::new();
+
+        let indices = mods
+            .iter()
+            .map(|spec| {
+                let mods: GameMods = match PyGameMods::extract(Some(spec), map.inner.mode, py) {
+                    Ok(PyGameMods::Lazer(mods)) => mods.into(),
+                    Ok(PyGameMods::Intermode(ref mods)) => mods.into(),
+                    Ok(PyGameMods::Legacy(mods)) => mods.into(),
+                    Err(err) => return Err(err),
+                };
+
+                let idx = match combinations
+                    .iter()
+                    .position(|combination| *combination == mods)
+                {
+                    Some(idx) => idx,
+                    None => {
+                        combinations.push(mods);
+
+                        combinations.len() - 1
+                    }
+                };
+
+                Ok(idx)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let attrs = py.detach(|| {
+            batch::map(&combinations, threads, |mods| {
+                base.clone().mods(mods.clone()).calculate(&map.inner)
+            })
+        });
+
+        let pairs = mods
+            .into_iter()
+            .zip(indices)
+            .map(|(spec, idx)| (spec, attrs[idx].clone().into()))
+            .collect();
+
+        Ok(pairs)
+    }
+
+    #[pyo3(signature = (map, rates, threads=None))]
+    fn stars_by_rate(
+        &self,
+        map: &PyBeatmap,
+        rates: Vec<f64>,
+        threads: Option<usize>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<PyDifficultyAttributes>> {
+        let threads = batch::threads(threads)?;
+
+        if rates.iter().any(|rate| !(rate.is_finite() && *rate > 0.0)) {
+            return Err(ArgsError::new_err("rates: must be positive numbers"));
+        }
+
+        let difficulty = self.try_as_difficulty(map.inner.mode, py)?;
+
+        let attrs = py.detach(|| {
+            batch::map(&rates, threads, |rate| {
+                difficulty.clone().clock_rate(*rate).calculate(&map.inner)
+            })
+        });
+
+        Ok(attrs.into_iter().map(From::from).collect())
     }
 
     fn strains(&self, map: &PyBeatmap, py: Python<'_>) -> PyResult<PyStrains> {
-        Ok(self
-            .try_as_difficulty(map.inner.mode, py)?
-            .strains(&map.inner)
-            .into())
+        let difficulty = self.try_as_difficulty(map.inner.mode, py)?;
+
+        Ok(py.detach(|| difficulty.strains(&map.inner)).into())
     }
 
     fn performance(&self, py: Python<'_>) -> PyPerformance {
@@ -174,6 +300,78 @@ impl PyDifficulty {
     pub fn set_hardrock_offsets(&mut self, hardrock_offsets: bool) {
         set!(self.hardrock_offsets = hardrock_offsets);
     }
+
+    #[getter]
+    pub fn get_mods(&self, py: Python<'_>) -> Option<Py<PyAny>> {
+        self.mods.as_ref().map(|mods| mods.clone_ref(py))
+    }
+
+    #[getter]
+    pub fn get_lazer(&self) -> Option<bool> {
+        self.inspect().lazer
+    }
+
+    #[getter]
+    pub fn get_clock_rate(&self) -> Option<f64> {
+        self.inspect().clock_rate
+    }
+
+    #[getter]
+    pub fn get_ar(&self) -> Option<f32> {
+        attribute(self.inspect().ar).0
+    }
+
+    #[getter]
+    pub fn get_fixed_ar(&self) -> bool {
+        attribute(self.inspect().ar).1
+    }
+
+    #[getter]
+    pub fn get_cs(&self) -> Option<f32> {
+        attribute(self.inspect().cs).0
+    }
+
+    #[getter]
+    pub fn get_fixed_cs(&self) -> bool {
+        attribute(self.inspect().cs).1
+    }
+
+    #[getter]
+    pub fn get_hp(&self) -> Option<f32> {
+        attribute(self.inspect().hp).0
+    }
+
+    #[getter]
+    pub fn get_fixed_hp(&self) -> bool {
+        attribute(self.inspect().hp).1
+    }
+
+    #[getter]
+    pub fn get_od(&self) -> Option<f32> {
+        attribute(self.inspect().od).0
+    }
+
+    #[getter]
+    pub fn get_fixed_od(&self) -> bool {
+        attribute(self.inspect().od).1
+    }
+
+    #[getter]
+    pub fn get_passed_objects(&self) -> Option<u32> {
+        self.inspect().passed_objects
+    }
+
+    #[getter]
+    pub fn get_hardrock_offsets(&self) -> Option<bool> {
+        self.inspect().hardrock_offsets
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        self.fill_dict(&dict)?;
+
+        Ok(dict)
+    }
 }
 
 impl PyDifficulty {
@@ -184,6 +382,62 @@ impl PyDifficulty {
         }
     }
 
+    fn inspect(&self) -> InspectDifficulty {
+        self.inner.clone().unwrap_or_default().inspect()
+    }
+
+    /// Inserts all specified settings into the dict, using the same keys as
+    /// the kwargs of the constructor.
+    pub(crate) fn fill_dict(&self, dict: &Bound<'_, PyDict>) -> PyResult<()> {
+        let InspectDifficulty {
+            mods: _,
+            passed_objects,
+            clock_rate,
+            ar,
+            cs,
+            hp,
+            od,
+            hardrock_offsets,
+            lazer,
+        } = self.inspect();
+
+        if let Some(ref mods) = self.mods {
+            dict.set_item("mods", mods)?;
+        }
+
+        if let Some(clock_rate) = clock_rate {
+            dict.set_item("clock_rate", clock_rate)?;
+        }
+
+        let attrs = [
+            ("ar", "fixed_ar", ar),
+            ("cs", "fixed_cs", cs),
+            ("hp", "fixed_hp", hp),
+            ("od", "fixed_od", od),
+        ];
+
+        for (key, fixed_key, attr) in attrs {
+            if let (Some(value), fixed) = attribute(attr) {
+                dict.set_item(key, value)?;
+                dict.set_item(fixed_key, fixed)?;
+            }
+        }
+
+        if let Some(passed_objects) = passed_objects {
+            dict.set_item("passed_objects", passed_objects)?;
+        }
+
+        if let Some(hardrock_offsets) = hardrock_offsets {
+            dict.set_item("hardrock_offsets", hardrock_offsets)?;
+        }
+
+        if let Some(lazer) = lazer {
+            dict.set_item("lazer", lazer)?;
+        }
+
+        Ok(())
+    }
+
     fn set_difficulty(&mut self, set: impl FnOnce(Difficulty) -> Difficulty) {
         self.inner = Some(set(self.inner.take().unwrap_or_default()));
     }
@@ -201,3 +455,12 @@ impl PyDifficulty {
         Ok(difficulty)
     }
 }
+
+/// The value of a specified attribute and whether it's fixed.
+fn attribute(attr: BeatmapAttribute) -> (Option<f32>, bool) {
+    match attr {
+        BeatmapAttribute::None => (None, false),
+        BeatmapAttribute::Value(value) | BeatmapAttribute::Given(value) => (Some(value), false),
+        BeatmapAttribute::Fixed(value) => (Some(value), true),
+    }
+}
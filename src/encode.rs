@@ -0,0 +1,444 @@
+//! Encoding a [`PyBeatmap`] back into the `.osu` file format.
+//!
+//! The output is laid out like a v14 file and contains everything that
+//! rosu-pp reads so that re-parsing it results in the same [`Beatmap`].
+
+use std::fmt::{Result as FmtResult, Write};
+
+use rosu_pp::{
+    model::{
+        control_point::{DifficultyPoint, EffectPoint, TimingPoint},
+        hit_object::{HitObject, HitObjectKind, PathType, Pos, Slider, SplineType},
+        mode::GameMode,
+    },
+    Beatmap,
+};
+
+use crate::{beatmap::PyBeatmap, decode::DEFAULT_METER, metadata::PyBeatmapMetadata};
+
+/// Format version written into the header.
+const FORMAT_VERSION: i32 = 14;
+
+/// The game shifts all times of files below this version so older maps are
+/// written with this version instead.
+const MIN_FORMAT_VERSION: i32 = 5;
+
+pub fn encode(map: &PyBeatmap) -> String {
+    let mut content = String::with_capacity(1024 + map.inner.hit_objects.len() * 32);
+
+    // Writing into a `String` cannot fail
+    let _ = Encoder {
+        map,
+        w: &mut content,
+    }
+    .encode();
+
+    content
+}
+
+struct Encoder<'a> {
+    map: &'a PyBeatmap,
+    w: &'a mut String,
+}
+
+impl Encoder<'_> {
+    fn encode(&mut self) -> FmtResult {
+        // The format version affects stacking and slider ticks so it's only
+        // raised for older maps if necessary
+        let version = self
+            .map
+            .inner
+            .version
+            .clamp(MIN_FORMAT_VERSION, FORMAT_VERSION);
+        writeln!(self.w, "osu file format v{version}")?;
+
+        self.w.push('\n');
+        self.encode_general()?;
+        self.w.push('\n');
+        self.encode_metadata()?;
+        self.w.push('\n');
+        self.encode_difficulty()?;
+        self.w.push('\n');
+        self.encode_events()?;
+        self.w.push('\n');
+        self.encode_timing_points()?;
+        self.w.push('\n');
+        self.encode_hit_objects()
+    }
+
+    fn encode_general(&mut self) -> FmtResult {
+        let Beatmap {
+            stack_leniency,
+            mode,
+            ..
+        } = self.map.inner;
+
+        let PyBeatmapMetadata {
+            ref audio_file,
+            audio_lead_in,
+            preview_time,
+            ..
+        } = self.map.metadata;
+
+        writeln!(
+            self.w,
+            "[General]
+AudioFilename: {audio_file}
+AudioLeadIn: {audio_lead_in}
+PreviewTime: {preview_time}
+StackLeniency: {stack_leniency}
+Mode: {mode}",
+            mode = mode as u8,
+        )
+    }
+
+    fn encode_metadata(&mut self) -> FmtResult {
+        let PyBeatmapMetadata {
+            title,
+            title_unicode,
+            artist,
+            artist_unicode,
+            creator,
+            version,
+            source,
+            tags,
+            beatmap_id,
+            beatmap_set_id,
+            ..
+        } = &self.map.metadata;
+
+        writeln!(
+            self.w,
+            "[Metadata]
+Title:{title}
+TitleUnicode:{title_unicode}
+Artist:{artist}
+ArtistUnicode:{artist_unicode}
+Creator:{creator}
+Version:{version}
+Source:{source}
+Tags:{tags}
+BeatmapID:{beatmap_id}
+BeatmapSetID:{beatmap_set_id}"
+        )
+    }
+
+    fn encode_difficulty(&mut self) -> FmtResult {
+        let Beatmap {
+            hp,
+            cs,
+            od,
+            ar,
+            slider_multiplier,
+            slider_tick_rate,
+            ..
+        } = self.map.inner;
+
+        writeln!(
+            self.w,
+            "[Difficulty]
+HPDrainRate:{hp}
+CircleSize:{cs}
+OverallDifficulty:{od}
+ApproachRate:{ar}
+SliderMultiplier:{slider_multiplier}
+SliderTickRate:{slider_tick_rate}"
+        )
+    }
+
+    fn encode_events(&mut self) -> FmtResult {
+        self.w.push_str("[Events]\n");
+
+        for period in self.map.inner.breaks.iter() {
+            writeln!(self.w, "2,{},{}", period.start_time, period.end_time)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes an uninherited line for every timing point and an inherited
+    /// line wherever the difficulty or effect point changes.
+    ///
+    /// Since each line produces all three kinds of control points on parsing,
+    /// inherited lines always carry the currently active values so that
+    /// redundant points are dropped again.
+    fn encode_timing_points(&mut self) -> FmtResult {
+        let map = &self.map.inner;
+
+        let mut times: Vec<_> = map
+            .timing_points
+            .iter()
+            .map(|point| point.time)
+            .chain(map.difficulty_points.iter().map(|point| point.time))
+            .chain(map.effect_points.iter().map(|point| point.time))
+            .collect();
+
+        times.sort_unstable_by(f64::total_cmp);
+        times.dedup_by(|a, b| a.total_cmp(b).is_eq());
+
+        self.w.push_str("[TimingPoints]\n");
+
+        let scroll_speed = matches!(map.mode, GameMode::Taiko | GameMode::Mania);
+
+        for time in times {
+            let timing = point_at(&map.timing_points, time, |point| point.time);
+            let difficulty = point_at(&map.difficulty_points, time, |point| point.time)
+                .cloned()
+                .unwrap_or_default();
+            let effect = point_at(&map.effect_points, time, |point| point.time)
+                .copied()
+                .unwrap_or_default();
+
+            let meter = timing.map_or(DEFAULT_METER, |point| self.meter_at(point.time));
+            let kiai = i32::from(effect.kiai);
+
+            let is_timing = timing.is_some_and(|point| point.time.total_cmp(&time).is_eq());
+
+            let needs_inherited = if is_timing {
+                let beat_len = timing.map_or(TimingPoint::DEFAULT_BEAT_LEN, |point| point.beat_len);
+                writeln!(self.w, "{time},{beat_len},{meter},0,0,100,1,{kiai}")?;
+
+                // Values that the uninherited line produces on its own
+                let parsed_difficulty = DifficultyPoint::new(time, beat_len, 1.0);
+                let parsed_scroll_speed = EffectPoint::DEFAULT_SCROLL_SPEED;
+
+                !difficulty.is_redundant(&parsed_difficulty)
+                    || difficulty.bpm_multiplier.to_bits()
+                        != parsed_difficulty.bpm_multiplier.to_bits()
+                    || (scroll_speed && effect.scroll_speed.ne(&parsed_scroll_speed))
+            } else {
+                true
+            };
+
+            if needs_inherited {
+                let beat_len = inherited_beat_len(&difficulty, &effect, scroll_speed);
+                writeln!(self.w, "{time},{beat_len},{meter},0,0,100,0,{kiai}")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn encode_hit_objects(&mut self) -> FmtResult {
+        self.w.push_str("[HitObjects]\n");
+
+        let map = &self.map.inner;
+
+        for (i, h) in map.hit_objects.iter().enumerate() {
+            let sound = map.hit_sounds.get(i).map_or(0, |sound| u8::from(*sound));
+            self.encode_hit_object(h, sound)?;
+        }
+
+        Ok(())
+    }
+
+    fn encode_hit_object(&mut self, h: &HitObject, sound: u8) -> FmtResult {
+        let HitObject {
+            pos,
+            start_time,
+            ref kind,
+        } = *h;
+
+        match kind {
+            HitObjectKind::Circle => {
+                writeln!(
+                    self.w,
+                    "{},{},{start_time},1,{sound},0:0:0:0:",
+                    pos.x, pos.y
+                )
+            }
+            HitObjectKind::Slider(slider) => {
+                write!(self.w, "{},{},{start_time},2,{sound},", pos.x, pos.y)?;
+                self.encode_slider(slider, pos)?;
+                self.w.push_str("0:0:0:0:\n");
+
+                Ok(())
+            }
+            HitObjectKind::Spinner(spinner) => writeln!(
+                self.w,
+                "{},{},{start_time},8,{sound},{},0:0:0:0:",
+                pos.x,
+                pos.y,
+                start_time + spinner.duration
+            ),
+            HitObjectKind::Hold(hold) => writeln!(
+                self.w,
+                "{},192,{start_time},128,{sound},{}:0:0:0:0:",
+                pos.x,
+                start_time + hold.duration
+            ),
+        }
+    }
+
+    // Mirrors rosu-map's encoding of slider paths
+    fn encode_slider(&mut self, slider: &Slider, pos: Pos) -> FmtResult {
+        let control_points = &slider.control_points;
+        let mut last_type = None;
+
+        let separator = |i: usize| {
+            if i == control_points.len() - 1 {
+                ','
+            } else {
+                '|'
+            }
+        };
+
+        for (i, point) in control_points.iter().enumerate() {
+            if let Some(path_type) = point.path_type {
+                let mut needs_explicit_segment =
+                    point.path_type != last_type || path_type == PathType::PERFECT_CURVE;
+
+                if i > 1 {
+                    let p1 = pos + control_points[i - 1].pos;
+                    let p2 = pos + control_points[i - 2].pos;
+
+                    if p1.x as i32 == p2.x as i32 && p1.y as i32 == p2.y as i32 {
+                        needs_explicit_segment = true;
+                    }
+                }
+
+                if needs_explicit_segment {
+                    match path_type.kind {
+                        SplineType::BSpline => match path_type.degree {
+                            Some(degree) => write!(self.w, "B{degree}")?,
+                            None => self.w.push('B'),
+                        },
+                        SplineType::Catmull => self.w.push('C'),
+                        SplineType::PerfectCurve => self.w.push('P'),
+                        SplineType::Linear => self.w.push('L'),
+                    }
+
+                    self.w.push(separator(i));
+                    last_type = Some(path_type);
+                } else {
+                    let point = pos + point.pos;
+                    write!(self.w, "{}:{}|", point.x, point.y)?;
+                }
+            }
+
+            if i != 0 {
+                let point = pos + point.pos;
+                write!(self.w, "{}:{}{}", point.x, point.y, separator(i))?;
+            }
+        }
+
+        // Zero is parsed as "no expected distance"
+        let dist = slider.expected_dist.unwrap_or(0.0);
+        write!(self.w, "{},{dist},", slider.repeats + 1)?;
+
+        for (i, sound) in slider.node_sounds.iter().enumerate() {
+            if i > 0 {
+                self.w.push('|');
+            }
+
+            write!(self.w, "{}", u8::from(*sound))?;
+        }
+
+        self.w.push(',');
+
+        for i in 0..slider.node_sounds.len() {
+            if i > 0 {
+                self.w.push('|');
+            }
+
+            self.w.push_str("0:0");
+        }
+
+        self.w.push(',');
+
+        Ok(())
+    }
+
+    fn meter_at(&self, time: f64) -> i32 {
+        let time_signatures = &self.map.time_signatures;
+
+        time_signatures
+            .binary_search_by(|probe| probe.0.total_cmp(&time))
+            .map_or(DEFAULT_METER, |i| time_signatures[i].1)
+    }
+}
+
+/// The last point at or before the given time.
+fn point_at<T>(points: &[T], time: f64, get_time: impl Fn(&T) -> f64) -> Option<&T> {
+    let i = points.partition_point(|point| get_time(point) <= time);
+
+    i.checked_sub(1).map(|i| &points[i])
+}
+
+/// Finds a negative beat length for an inherited line that reproduces the
+/// given points exactly when parsed.
+///
+/// The slider velocity and bpm multiplier are clamped differently when
+/// parsing so the beat length can't always be derived from just one of them.
+fn inherited_beat_len(
+    difficulty: &DifficultyPoint,
+    effect: &EffectPoint,
+    scroll_speed: bool,
+) -> f64 {
+    let matches = |beat_len: f64| {
+        let speed_multiplier = 100.0 / -beat_len;
+        let parsed = DifficultyPoint::new(0.0, beat_len, speed_multiplier);
+
+        let difficulty_matches = parsed.slider_velocity.to_bits()
+            == difficulty.slider_velocity.to_bits()
+            && parsed.bpm_multiplier.to_bits() == difficulty.bpm_multiplier.to_bits()
+            && parsed.generate_ticks == difficulty.generate_ticks;
+
+        let effect_matches = !scroll_speed
+            || speed_multiplier.clamp(0.01, 10.0).to_bits() == effect.scroll_speed.to_bits();
+
+        (difficulty_matches, effect_matches)
+    };
+
+    let base = [
+        -100.0 / difficulty.slider_velocity,
+        -100.0 * difficulty.bpm_multiplier,
+        -100.0 / effect.scroll_speed,
+    ];
+
+    let candidates = || {
+        base.into_iter()
+            .flat_map(|beat_len| [beat_len, beat_len.next_up(), beat_len.next_down()])
+    };
+
+    candidates()
+        .find(|&beat_len| matches(beat_len) == (true, true))
+        .or_else(|| candidates().find(|&beat_len| matches(beat_len).0))
+        .unwrap_or(base[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = include_str!("../tests/fixtures/test_map.osu");
+
+    fn decode(content: &str) -> PyBeatmap {
+        let decoded = rosu_map::from_str::<crate::decode::DecodedBeatmap>(content).unwrap();
+
+        PyBeatmap::decoded(decoded, "0123456789abcdef0123456789abcdef".to_owned())
+    }
+
+    #[test]
+    fn roundtrip() {
+        let map = decode(MAP);
+        let encoded = decode(&encode(&map));
+
+        assert_eq!(map.inner, encoded.inner);
+        assert_eq!(map.time_signatures, encoded.time_signatures);
+        assert_eq!(map.metadata, encoded.metadata);
+    }
+
+    #[test]
+    fn clamped_slider_velocity() {
+        let map = decode(
+            "osu file format v14\n\n[TimingPoints]\n0,500,3,0,0,100,1,0\n\
+            100,-5000,4,0,0,100,0,1\n200,-3,4,0,0,100,0,0\n",
+        );
+        let encoded = decode(&encode(&map));
+
+        assert_eq!(map.inner.difficulty_points, encoded.inner.difficulty_points);
+        assert_eq!(map.inner.effect_points, encoded.inner.effect_points);
+        assert_eq!(map.time_signatures, encoded.time_signatures);
+    }
+}
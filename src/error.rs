@@ -1,5 +1,60 @@
-use pyo3::{create_exception, exceptions::PyException};
+use pyo3::{
+    create_exception,
+    exceptions::PyException,
+    intern,
+    types::{PyAnyMethods, PyCFunction, PyDict, PyDictMethods, PyList, PyTuple},
+    Bound, Py, PyAny, PyErr, PyResult, Python,
+};
 
 create_exception!(rosu_pp_py, ArgsError, PyException);
 create_exception!(rosu_pp_py, ParseError, PyException);
 create_exception!(rosu_pp_py, ConvertError, PyException);
+
+/// Turns `ParseError.causes` into a property so that each instance lazily
+/// gets its own list instead of sharing one through the class.
+pub fn init_parse_error_causes(py: Python<'_>) -> PyResult<()> {
+    fn instance_dict<'py>(args: &Bound<'py, PyTuple>) -> PyResult<Bound<'py, PyDict>> {
+        let py = args.py();
+        let dict = args.get_item(0)?.getattr(intern!(py, "__dict__"))?;
+
+        Ok(dict.cast_into::<PyDict>()?)
+    }
+
+    let getter = PyCFunction::new_closure(py, None, None, |args, _| -> PyResult<Py<PyAny>> {
+        let py = args.py();
+        let dict = instance_dict(args)?;
+
+        if let Some(causes) = dict.get_item(intern!(py, "causes"))? {
+            return Ok(causes.unbind());
+        }
+
+        let causes = PyList::empty(py).into_any();
+        dict.set_item(intern!(py, "causes"), &causes)?;
+
+        Ok(causes.unbind())
+    })?;
+
+    let setter = PyCFunction::new_closure(py, None, None, |args, _| {
+        let py = args.py();
+
+        instance_dict(args)?.set_item(intern!(py, "causes"), args.get_item(1)?)
+    })?;
+
+    let property = py
+        .import(intern!(py, "builtins"))?
+        .getattr(intern!(py, "property"))?
+        .call1((getter, setter))?;
+
+    py.get_type::<ParseError>()
+        .setattr(intern!(py, "causes"), property)
+}
+
+/// A [`ParseError`] whose only cause is its message.
+pub fn parse_error_from_msg(py: Python<'_>, msg: String) -> PyErr {
+    let err = ParseError::new_err(msg.clone());
+
+    match err.value(py).setattr(intern!(py, "causes"), [msg]) {
+        Ok(()) => err,
+        Err(err) => err,
+    }
+}
@@ -16,17 +16,17 @@ impl PyGradualDifficulty {
     pub fn new(difficulty: &PyDifficulty, map: &PyBeatmap, py: Python<'_>) -> PyResult<Self> {
         let difficulty = difficulty.try_as_difficulty(map.inner.mode, py)?;
 
-        Ok(Self {
-            inner: GradualDifficulty::new(difficulty, &map.inner),
-        })
+        let inner = py.detach(|| GradualDifficulty::new(difficulty, &map.inner));
+
+        Ok(Self { inner })
     }
 
-    fn next(&mut self) -> Option<PyDifficultyAttributes> {
-        self.inner.next().map(From::from)
+    fn next(&mut self, py: Python<'_>) -> Option<PyDifficultyAttributes> {
+        py.detach(|| self.inner.next()).map(From::from)
     }
 
-    fn nth(&mut self, n: usize) -> Option<PyDifficultyAttributes> {
-        self.inner.nth(n).map(From::from)
+    fn nth(&mut self, n: usize, py: Python<'_>) -> Option<PyDifficultyAttributes> {
+        py.detach(|| self.inner.nth(n)).map(From::from)
     }
 
     #[getter]
@@ -38,7 +38,7 @@ impl PyGradualDifficulty {
         this
     }
 
-    fn __next__(&mut self) -> Option<PyDifficultyAttributes> {
-        self.next()
+    fn __next__(&mut self, py: Python<'_>) -> Option<PyDifficultyAttributes> {
+        self.next(py)
     }
 }
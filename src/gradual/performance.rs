@@ -17,17 +17,26 @@ impl PyGradualPerformance {
     pub fn new(difficulty: &PyDifficulty, map: &PyBeatmap, py: Python<'_>) -> PyResult<Self> {
         let difficulty = difficulty.try_as_difficulty(map.inner.mode, py)?;
 
-        Ok(Self {
-            inner: GradualPerformance::new(difficulty, &map.inner),
-        })
+        let inner = py.detach(|| GradualPerformance::new(difficulty, &map.inner));
+
+        Ok(Self { inner })
     }
 
-    fn next(&mut self, state: &PyScoreState) -> Option<PyPerformanceAttributes> {
-        self.inner.next(state.into()).map(From::from)
+    fn next(&mut self, state: &PyScoreState, py: Python<'_>) -> Option<PyPerformanceAttributes> {
+        let state = state.into();
+
+        py.detach(|| self.inner.next(state)).map(From::from)
     }
 
-    fn nth(&mut self, state: &PyScoreState, n: usize) -> Option<PyPerformanceAttributes> {
-        self.inner.nth(state.into(), n).map(From::from)
+    fn nth(
+        &mut self,
+        state: &PyScoreState,
+        n: usize,
+        py: Python<'_>,
+    ) -> Option<PyPerformanceAttributes> {
+        let state = state.into();
+
+        py.detach(|| self.inner.nth(state, n)).map(From::from)
     }
 
     #[getter]
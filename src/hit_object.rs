@@ -0,0 +1,188 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use pyo3::pyclass;
+use rosu_map::section::hit_objects::{BorrowedCurve, CurveBuffers};
+use rosu_pp::{
+    model::{
+        control_point::{DifficultyPoint, TimingPoint},
+        hit_object::{HitObject, HitObjectKind, PathControlPoint, PathType, Slider, SplineType},
+    },
+    Beatmap,
+};
+
+type ControlPointList = Vec<PyPathControlPoint>;
+
+define_class! {
+    #[pyclass(name = "HitObject", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyHitObject {
+        pub kind: PyHitObjectKind!,
+        pub start_time: f64!,
+        pub end_time: f64!,
+        pub x: f32!,
+        pub y: f32!,
+        pub repeats: usize?,
+        pub length: f64?,
+        pub control_points: ControlPointList?,
+    }
+}
+
+impl PyHitObject {
+    pub(crate) fn new(h: &HitObject, map: &Beatmap, bufs: &mut CurveBuffers) -> Self {
+        let (end_time, length) = end_time_and_length(h, map, bufs);
+
+        let (kind, repeats, control_points) = match h.kind {
+            HitObjectKind::Circle => (PyHitObjectKind::Circle, None, None),
+            HitObjectKind::Slider(ref slider) => {
+                let control_points = slider
+                    .control_points
+                    .iter()
+                    .map(PyPathControlPoint::from)
+                    .collect();
+
+                (
+                    PyHitObjectKind::Slider,
+                    Some(slider.repeats),
+                    Some(control_points),
+                )
+            }
+            HitObjectKind::Spinner(_) => (PyHitObjectKind::Spinner, None, None),
+            HitObjectKind::Hold(_) => (PyHitObjectKind::Hold, None, None),
+        };
+
+        Self {
+            kind,
+            start_time: h.start_time,
+            end_time,
+            x: h.pos.x,
+            y: h.pos.y,
+            repeats,
+            length,
+            control_points,
+        }
+    }
+}
+
+/// The end time of a hitobject, including the duration of sliders.
+pub(crate) fn end_time(h: &HitObject, map: &Beatmap, bufs: &mut CurveBuffers) -> f64 {
+    end_time_and_length(h, map, bufs).0
+}
+
+/// The end time of a hitobject and, for sliders, the length of their path.
+fn end_time_and_length(
+    h: &HitObject,
+    map: &Beatmap,
+    bufs: &mut CurveBuffers,
+) -> (f64, Option<f64>) {
+    match h.kind {
+        HitObjectKind::Circle => (h.start_time, None),
+        HitObjectKind::Slider(ref slider) => {
+            let curve =
+                BorrowedCurve::new(map.mode, &slider.control_points, slider.expected_dist, bufs);
+
+            let length = curve.dist();
+
+            (
+                slider_end_time(h.start_time, slider, length, map),
+                Some(length),
+            )
+        }
+        HitObjectKind::Spinner(spinner) => (h.start_time + spinner.duration, None),
+        HitObjectKind::Hold(hold) => (h.start_time + hold.duration, None),
+    }
+}
+
+// Same computation as rosu-pp's `OsuSlider`
+fn slider_end_time(start_time: f64, slider: &Slider, length: f64, map: &Beatmap) -> f64 {
+    /// Distance a slider travels in one beat at a slider multiplier of 1.
+    const BASE_SCORING_DIST: f64 = 100.0;
+
+    let beat_len = timing_point_at(&map.timing_points, start_time)
+        .map_or(TimingPoint::DEFAULT_BEAT_LEN, |point| point.beat_len);
+
+    let slider_velocity = difficulty_point_at(&map.difficulty_points, start_time)
+        .map_or(DifficultyPoint::DEFAULT_SLIDER_VELOCITY, |point| {
+            point.slider_velocity
+        });
+
+    let velocity = BASE_SCORING_DIST * map.slider_multiplier
+        / precision_adjusted_beat_len(slider_velocity, beat_len);
+
+    start_time + slider.span_count() as f64 * length / velocity
+}
+
+fn precision_adjusted_beat_len(slider_velocity: f64, beat_len: f64) -> f64 {
+    let slider_velocity_as_beat_len = -100.0 / slider_velocity;
+
+    let bpm_multiplier = if slider_velocity_as_beat_len < 0.0 {
+        f64::from(((-slider_velocity_as_beat_len) as f32).clamp(10.0, 10_000.0)) / 100.0
+    } else {
+        1.0
+    };
+
+    beat_len * bpm_multiplier
+}
+
+fn timing_point_at(points: &[TimingPoint], time: f64) -> Option<&TimingPoint> {
+    let i = points
+        .binary_search_by(|probe| probe.time.total_cmp(&time))
+        .unwrap_or_else(|i| i.saturating_sub(1));
+
+    points.get(i)
+}
+
+fn difficulty_point_at(points: &[DifficultyPoint], time: f64) -> Option<&DifficultyPoint> {
+    points
+        .binary_search_by(|probe| probe.time.total_cmp(&time))
+        .map_or_else(|i| i.checked_sub(1), Some)
+        .map(|i| &points[i])
+}
+
+#[pyclass(eq, eq_int, name = "HitObjectKind", from_py_object)]
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum PyHitObjectKind {
+    #[default]
+    Circle,
+    Slider,
+    Spinner,
+    Hold,
+}
+
+impl Debug for PyHitObjectKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.__pyo3__repr__())
+    }
+}
+
+define_class! {
+    #[pyclass(name = "PathControlPoint", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyPathControlPoint {
+        pub x: f32!,
+        pub y: f32!,
+        pub path_type: String?,
+    }
+}
+
+impl From<&PathControlPoint> for PyPathControlPoint {
+    fn from(point: &PathControlPoint) -> Self {
+        Self {
+            x: point.pos.x,
+            y: point.pos.y,
+            path_type: point.path_type.map(path_type_str),
+        }
+    }
+}
+
+/// Formats a [`PathType`] the same way it's written in `.osu` files.
+fn path_type_str(path_type: PathType) -> String {
+    match path_type.kind {
+        SplineType::Catmull => "C".to_owned(),
+        SplineType::BSpline => match path_type.degree {
+            Some(degree) => format!("B{degree}"),
+            None => "B".to_owned(),
+        },
+        SplineType::Linear => "L".to_owned(),
+        SplineType::PerfectCurve => "P".to_owned(),
+    }
+}
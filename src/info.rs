@@ -0,0 +1,254 @@
+//! Light-weight parsing of a `.osu` file's header.
+
+use pyo3::pyclass;
+use rosu_map::{
+    section::{hit_objects::HitObjectType, metadata::Metadata, Section},
+    DecodeBeatmap, DecodeState,
+};
+use rosu_pp::{model::beatmap::BeatmapState, Beatmap};
+
+use crate::{
+    decode::{self, LimitExceeded, ParseLimits},
+    mode::PyGameMode,
+};
+
+define_class! {
+    #[pyclass(name = "BeatmapInfo", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyBeatmapInfo {
+        pub mode: PyGameMode!,
+        pub format_version: i32!,
+        pub difficulty_name: String!,
+        pub ar: f32!,
+        pub cs: f32!,
+        pub hp: f32!,
+        pub od: f32!,
+        pub n_objects: usize!,
+        pub n_circles: usize!,
+        pub n_sliders: usize!,
+        pub n_spinners: usize!,
+        pub n_holds: usize!,
+    }
+}
+
+/// Parses the `[General]`, `[Metadata]`, and `[Difficulty]` sections and
+/// counts hit objects by their type without parsing them any further.
+///
+/// Once all three sections have been parsed, the following lines are only
+/// checked for the start of the `[HitObjects]` section.
+pub fn from_bytes(bytes: &[u8], limits: &ParseLimits) -> Result<PyBeatmapInfo, LimitExceeded> {
+    decode::check_file_size(bytes, limits)?;
+
+    let content = decode::decode_text(bytes);
+    let mut lines = decode::numbered_lines(&content);
+    let (version, section) = decode::decode_header(&mut lines);
+
+    let mut state = BeatmapState::create(version);
+    let mut metadata = Metadata::default();
+    let mut counts = [0; 4];
+
+    if let Some(mut section) = section {
+        let mut parsed = [false; 3];
+        let mut counted = false;
+
+        if let Some(idx) = parsed_section_index(section) {
+            parsed[idx] = true;
+        }
+
+        for (_, line) in lines.by_ref() {
+            if Beatmap::should_skip_line(line) {
+                continue;
+            }
+
+            if let Some(next) = Section::try_from_line(line) {
+                section = next;
+
+                if let Some(idx) = parsed_section_index(section) {
+                    parsed[idx] = true;
+                } else if parsed.iter().all(|parsed| *parsed) && section != Section::HitObjects {
+                    break;
+                }
+
+                continue;
+            }
+
+            // Same as rosu-map, invalid lines are skipped
+            match section {
+                Section::General => {
+                    let _ = Beatmap::parse_general(&mut state, line);
+                }
+                Section::Metadata => {
+                    let _ = Metadata::parse_metadata(&mut metadata, line);
+                }
+                Section::Difficulty => {
+                    let _ = Beatmap::parse_difficulty(&mut state, line);
+                }
+                Section::HitObjects => {
+                    counted = true;
+                    count_object(line, &mut counts);
+                }
+                _ => {}
+            }
+        }
+
+        if !counted {
+            let _ = lines
+                .by_ref()
+                .find(|(_, line)| Section::try_from_line(line) == Some(Section::HitObjects));
+
+            for (_, line) in lines {
+                if Section::try_from_line(line).is_some() {
+                    break;
+                }
+
+                count_object(line, &mut counts);
+            }
+        }
+    }
+
+    // Without hit objects or timing points this conversion is cheap
+    let map = Beatmap::from(state);
+    let [n_circles, n_sliders, n_spinners, n_holds] = counts;
+
+    Ok(PyBeatmapInfo {
+        mode: PyGameMode::from(map.mode),
+        format_version: map.version,
+        difficulty_name: metadata.version,
+        ar: map.ar,
+        cs: map.cs,
+        hp: map.hp,
+        od: map.od,
+        n_objects: counts.iter().sum(),
+        n_circles,
+        n_sliders,
+        n_spinners,
+        n_holds,
+    })
+}
+
+/// Index of the sections that need to be parsed in the order general,
+/// metadata, difficulty.
+fn parsed_section_index(section: Section) -> Option<usize> {
+    match section {
+        Section::General => Some(0),
+        Section::Metadata => Some(1),
+        Section::Difficulty => Some(2),
+        _ => None,
+    }
+}
+
+fn count_object(line: &str, counts: &mut [usize; 4]) {
+    if let Some(idx) = object_type_index(line) {
+        counts[idx] += 1;
+    }
+}
+
+/// Index of a hit object line's type in the order circle, slider, spinner,
+/// hold.
+fn object_type_index(line: &str) -> Option<usize> {
+    let kind: HitObjectType = line.split(',').nth(3)?.parse().ok()?;
+
+    // Flags are checked in the same order as rosu-pp does
+    [
+        HitObjectType::CIRCLE,
+        HitObjectType::SLIDER,
+        HitObjectType::SPINNER,
+        HitObjectType::HOLD,
+    ]
+    .into_iter()
+    .position(|flag| kind.has_flag(flag))
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_pp::model::hit_object::HitObjectKind;
+
+    use super::*;
+
+    const MAP: &str = include_str!("../tests/fixtures/test_map.osu");
+
+    const MANIA: &str = "osu file format v14
+
+[General]
+Mode: 3
+
+[Difficulty]
+CircleSize:4
+OverallDifficulty:8
+
+[HitObjects]
+64,192,1000,1,0,0:0:0:0:
+192,192,1500,128,0,2000:0:0:0:0:
+320,192,2500,1,0,0:0:0:0:
+";
+
+    fn assert_matches(content: &str) {
+        let info = from_bytes(content.as_bytes(), &ParseLimits::default()).unwrap();
+        let map = Beatmap::from_bytes(content.as_bytes()).unwrap();
+
+        let count =
+            |f: fn(&HitObjectKind) -> bool| map.hit_objects.iter().filter(|h| f(&h.kind)).count();
+
+        assert_eq!(info.mode, PyGameMode::from(map.mode));
+        assert_eq!(info.format_version, map.version);
+        assert_eq!(
+            (info.ar, info.cs, info.hp, info.od),
+            (map.ar, map.cs, map.hp, map.od)
+        );
+        assert_eq!(info.n_objects, map.hit_objects.len());
+        assert_eq!(
+            info.n_circles,
+            count(|kind| matches!(kind, HitObjectKind::Circle))
+        );
+        assert_eq!(
+            info.n_sliders,
+            count(|kind| matches!(kind, HitObjectKind::Slider(_)))
+        );
+        assert_eq!(
+            info.n_spinners,
+            count(|kind| matches!(kind, HitObjectKind::Spinner(_)))
+        );
+        assert_eq!(
+            info.n_holds,
+            count(|kind| matches!(kind, HitObjectKind::Hold(_)))
+        );
+    }
+
+    #[test]
+    fn matches_beatmap() {
+        assert_matches(MAP);
+    }
+
+    #[test]
+    fn matches_beatmap_mania() {
+        assert_matches(MANIA);
+    }
+
+    #[test]
+    fn sections_in_any_order() {
+        let content = "osu file format v14
+
+[Metadata]
+Version:Insane
+
+[Difficulty]
+CircleSize:4
+
+[General]
+Mode: 3
+
+[HitObjects]
+64,192,1000,1,0,0:0:0:0:
+
+[Colours]
+Combo1 : 255,0,0
+";
+
+        let info = from_bytes(content.as_bytes(), &ParseLimits::default()).unwrap();
+
+        assert_eq!(info.mode, PyGameMode::Mania);
+        assert_eq!(info.difficulty_name, "Insane");
+        assert_eq!(info.cs, 4.0);
+        assert_eq!(info.n_objects, 1);
+    }
+}
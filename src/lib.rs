@@ -1,7 +1,11 @@
 use error::ConvertError;
 use performance::PyHitResultPriority;
 use pyo3::prelude::PyModuleMethods;
-use pyo3::{pymodule, types::PyModule, Bound, PyResult, Python};
+use pyo3::{
+    pymodule,
+    types::{PyAnyMethods, PyModule},
+    Bound, PyResult, Python,
+};
 
 use self::{
     attributes::{
@@ -10,28 +14,54 @@ use self::{
         performance::PyPerformanceAttributes,
     },
     beatmap::PyBeatmap,
+    bpm::PyBpmStats,
+    break_period::PyBreakPeriod,
+    control_point::{PyDifficultyPoint, PyEffectPoint, PyTimingPoint},
     difficulty::PyDifficulty,
     error::{ArgsError, ParseError},
     gradual::{difficulty::PyGradualDifficulty, performance::PyGradualPerformance},
+    hit_object::{PyHitObject, PyHitObjectKind, PyPathControlPoint},
+    info::PyBeatmapInfo,
+    mania::PyManiaColumn,
+    metadata::PyBeatmapMetadata,
     mode::PyGameMode,
+    parse_warning::PyParseWarning,
     performance::{PyHitResultGenerator, PyPerformance},
     score_state::PyScoreState,
     strains::PyStrains,
+    suspicion::{PySuspicionReason, PySuspicionReport},
 };
 
 #[macro_use]
 mod macros;
 
 mod attributes;
+mod batch;
 mod beatmap;
+mod bpm;
+mod break_period;
+mod buffer;
+mod control_point;
+mod decode;
 mod difficulty;
+mod encode;
 mod error;
 mod gradual;
+mod hit_object;
+mod info;
+mod mania;
+mod md5;
+mod metadata;
 mod mode;
 mod mods;
+mod osz;
+mod parse_warning;
 mod performance;
+mod pickle;
 mod score_state;
 mod strains;
+mod suspicion;
+mod transform;
 
 #[pymodule]
 fn rosu_pp_py(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -43,6 +73,8 @@ fn rosu_pp_py(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyBeatmapAttributesBuilder>()?;
 
     m.add_class::<PyGameMode>()?;
+    m.add_class::<PyHitObjectKind>()?;
+    m.add_class::<PySuspicionReason>()?;
     m.add_class::<PyScoreState>()?;
     m.add_class::<PyHitResultPriority>()?;
     m.add_class::<PyHitResultGenerator>()?;
@@ -51,8 +83,29 @@ fn rosu_pp_py(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyDifficultyAttributes>()?;
     m.add_class::<PyPerformanceAttributes>()?;
     m.add_class::<PyStrains>()?;
+    m.add_class::<PyHitObject>()?;
+    m.add_class::<PyPathControlPoint>()?;
+    m.add_class::<PyTimingPoint>()?;
+    m.add_class::<PyDifficultyPoint>()?;
+    m.add_class::<PyEffectPoint>()?;
+    m.add_class::<PyBreakPeriod>()?;
+    m.add_class::<PyBpmStats>()?;
+    m.add_class::<PyBeatmapInfo>()?;
+    m.add_class::<PyManiaColumn>()?;
+    m.add_class::<PyBeatmapMetadata>()?;
+    m.add_class::<PyParseWarning>()?;
+    m.add_class::<PySuspicionReport>()?;
+
+    let parse_error = py.get_type::<ParseError>();
+
+    // Beatmap parsing errors overwrite these on the instance
+    for attr in ["section", "line_number", "line"] {
+        parse_error.setattr(attr, py.None())?;
+    }
+
+    error::init_parse_error_causes(py)?;
 
-    m.add("ParseError", py.get_type::<ParseError>())?;
+    m.add("ParseError", parse_error)?;
     m.add("ArgsError", py.get_type::<ArgsError>())?;
     m.add("ConvertError", py.get_type::<ConvertError>())?;
 
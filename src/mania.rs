@@ -0,0 +1,68 @@
+//! Column layout of osu!mania beatmaps.
+
+use pyo3::pyclass;
+use rosu_pp::{
+    model::{hit_object::HitObjectKind, mode::GameMode},
+    Beatmap,
+};
+
+/// Highest key count supported by osu!mania, i.e. two stages of ten keys.
+const MAX_COLUMNS: f32 = 20.0;
+
+define_class! {
+    #[pyclass(name = "ManiaColumn", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PyManiaColumn {
+        pub column: usize!,
+        pub notes: usize!,
+        pub holds: usize!,
+    }
+}
+
+/// The amount of keys of an osu!mania map, same as rosu-pp computes it.
+pub fn total_columns(map: &Beatmap) -> f32 {
+    map.cs.round_ties_even().max(1.0)
+}
+
+/// The zero-based column of an osu!mania object, same as rosu-pp computes
+/// it.
+pub fn column(x: f32, total_columns: f32) -> usize {
+    let divisor = 512.0 / total_columns;
+
+    (x / divisor).floor().min(total_columns - 1.0) as usize
+}
+
+/// Counts notes and hold notes per column.
+///
+/// Returns `None` if the map is not an osu!mania map or if its key count is
+/// not supported by osu!mania.
+pub fn columns(map: &Beatmap) -> Option<Vec<PyManiaColumn>> {
+    if map.mode != GameMode::Mania {
+        return None;
+    }
+
+    let total_columns = total_columns(map);
+
+    if total_columns > MAX_COLUMNS {
+        return None;
+    }
+
+    let mut columns: Vec<_> = (0..total_columns as usize)
+        .map(|column| PyManiaColumn {
+            column,
+            notes: 0,
+            holds: 0,
+        })
+        .collect();
+
+    for h in map.hit_objects.iter() {
+        let column = &mut columns[column(h.pos.x, total_columns)];
+
+        match h.kind {
+            HitObjectKind::Hold(_) => column.holds += 1,
+            _ => column.notes += 1,
+        }
+    }
+
+    Some(columns)
+}
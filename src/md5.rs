@@ -0,0 +1,132 @@
+//! MD5 digest as specified in [RFC 1321].
+//!
+//! MD5 is only used to identify beatmap files the same way the game does,
+//! not for anything security related.
+//!
+//! [RFC 1321]: https://www.rfc-editor.org/rfc/rfc1321
+
+use std::fmt::Write;
+
+/// Per-round shift amounts.
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// Integer parts of the sines of integers (in radians).
+const K: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// Computes the MD5 digest of the given bytes as lowercase hexadecimal
+/// string.
+pub fn hex_digest(data: &[u8]) -> String {
+    digest(data)
+        .iter()
+        .fold(String::with_capacity(32), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+
+            hex
+        })
+}
+
+fn digest(data: &[u8]) -> [u8; 16] {
+    let mut state = [0x67452301_u32, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+    let mut chunks = data.chunks_exact(64);
+
+    for chunk in chunks.by_ref() {
+        process_block(&mut state, chunk);
+    }
+
+    // Padding: a single 1 bit, zeros, and the message length in bits
+    let rest = chunks.remainder();
+    let mut tail = [0; 128];
+    tail[..rest.len()].copy_from_slice(rest);
+    tail[rest.len()] = 0x80;
+
+    let tail_len = if rest.len() < 56 { 64 } else { 128 };
+    let bit_len = (data.len() as u64).wrapping_mul(8);
+    tail[tail_len - 8..tail_len].copy_from_slice(&bit_len.to_le_bytes());
+
+    for chunk in tail[..tail_len].chunks_exact(64) {
+        process_block(&mut state, chunk);
+    }
+
+    let mut digest = [0; 16];
+
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+
+    digest
+}
+
+fn process_block(state: &mut [u32; 4], block: &[u8]) {
+    let mut words = [0; 16];
+
+    for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+
+    let [mut a, mut b, mut c, mut d] = *state;
+
+    for i in 0..64 {
+        let (f, g) = match i / 16 {
+            0 => ((b & c) | (!b & d), i),
+            1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+            2 => (b ^ c ^ d, (3 * i + 5) % 16),
+            _ => (c ^ (b | !d), (7 * i) % 16),
+        };
+
+        let f = f.wrapping_add(a).wrapping_add(K[i]).wrapping_add(words[g]);
+
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(f.rotate_left(SHIFTS[i]));
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc_test_suite() {
+        assert_eq!(hex_digest(b""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(hex_digest(b"a"), "0cc175b9c0f1b6a831c399e269772661");
+        assert_eq!(hex_digest(b"abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex_digest(b"abcdefghijklmnopqrstuvwxyz"),
+            "c3fcd3d76192e4007dfb496cca67e13b"
+        );
+        assert_eq!(
+            hex_digest(
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+            ),
+            "57edf4a22be3c955ac49da2e2107b67a"
+        );
+    }
+
+    #[test]
+    fn padding_boundaries() {
+        // Lengths around the 56 byte boundary need an additional block
+        assert_eq!(hex_digest(&[b'a'; 55]), "ef1772b6dff9a122358552954ad0df65");
+        assert_eq!(hex_digest(&[b'a'; 56]), "3b0c8ac703f828b04c6c197006d17218");
+        assert_eq!(hex_digest(&[b'a'; 64]), "014842d480b571495a4a0363793f7367");
+    }
+}
@@ -0,0 +1,55 @@
+use pyo3::pyclass;
+use rosu_map::section::{general::General, metadata::Metadata};
+
+define_class! {
+    #[pyclass(name = "BeatmapMetadata", frozen, skip_from_py_object)]
+    #[derive(Clone, Default, PartialEq)]
+    pub struct PyBeatmapMetadata {
+        pub title: String!,
+        pub title_unicode: String!,
+        pub artist: String!,
+        pub artist_unicode: String!,
+        pub creator: String!,
+        pub version: String!,
+        pub source: String!,
+        pub tags: String!,
+        pub beatmap_id: i32!,
+        pub beatmap_set_id: i32!,
+        pub audio_file: String!,
+        pub audio_lead_in: f64!,
+        pub preview_time: i32!,
+    }
+}
+
+impl PyBeatmapMetadata {
+    pub(crate) fn new(metadata: Metadata, general: General) -> Self {
+        let Metadata {
+            title,
+            title_unicode,
+            artist,
+            artist_unicode,
+            creator,
+            version,
+            source,
+            tags,
+            beatmap_id,
+            beatmap_set_id,
+        } = metadata;
+
+        Self {
+            title,
+            title_unicode,
+            artist,
+            artist_unicode,
+            creator,
+            version,
+            source,
+            tags,
+            beatmap_id,
+            beatmap_set_id,
+            audio_file: general.audio_file,
+            audio_lead_in: general.audio_lead_in,
+            preview_time: general.preview_time,
+        }
+    }
+}
@@ -3,8 +3,8 @@ use std::fmt::{Debug, Formatter, Result as FmtResult};
 use pyo3::pyclass;
 use rosu_pp::model::mode::GameMode;
 
-#[pyclass(eq, eq_int, name = "GameMode", from_py_object)]
-#[derive(Copy, Clone, Default, PartialEq)]
+#[pyclass(eq, eq_int, hash, frozen, name = "GameMode", from_py_object)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
 pub enum PyGameMode {
     #[default]
     Osu,
@@ -90,6 +90,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_copy_clone() {
         let mode = PyGameMode::Taiko;
         let copied = mode;
@@ -23,7 +23,7 @@ use serde::de::{
     DeserializeSeed, Deserializer, Error as DeError, MapAccess, Unexpected, Visitor,
 };
 
-use crate::error::ParseError;
+use crate::error::parse_error_from_msg;
 
 #[derive(Clone)]
 pub enum PyGameMods {
@@ -75,7 +75,7 @@ impl PyGameMods {
 
                     match seed.deserialize(&gamemod) {
                         Ok(gamemod) => return Ok(Self::Lazer(gamemod.into())),
-                        Err(DeserializeError(err)) => ParseError::new_err(err),
+                        Err(DeserializeError(err)) => parse_error_from_msg(obj.py(), err),
                     }
                 }
                 Err(err) => err,
@@ -102,7 +102,9 @@ impl PyGameMods {
 
                             match res {
                                 Ok(gamemod) => mods.insert(gamemod),
-                                Err(DeserializeError(err)) => return Err(ParseError::new_err(err)),
+                                Err(DeserializeError(err)) => {
+                                    return Err(parse_error_from_msg(obj.py(), err))
+                                }
                             }
 
                             Ok(mods)
@@ -0,0 +1,182 @@
+//! Reading `.osu` files of `.osz` archives through python's `zipfile`.
+
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use pyo3::{intern, types::PyAnyMethods, Bound, PyAny, PyErr, PyResult};
+
+use crate::{
+    buffer,
+    decode::{LimitExceeded, ParseLimits},
+};
+
+/// Default limit for the total size of all decompressed `.osu` files of an
+/// archive.
+pub const DEFAULT_MAX_TOTAL_SIZE: u64 = 256 * 1024 * 1024;
+
+/// The archive as a whole could not be read.
+#[derive(Debug)]
+pub enum OszError {
+    Archive(PyErr),
+    TotalSize { limit: u64 },
+}
+
+impl StdError for OszError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Archive(err) => Some(err),
+            Self::TotalSize { .. } => None,
+        }
+    }
+}
+
+impl Display for OszError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Archive(_) => f.write_str("invalid archive"),
+            Self::TotalSize { limit } => write!(
+                f,
+                "decompressed .osu files exceed the total size limit of {limit} bytes"
+            ),
+        }
+    }
+}
+
+impl From<PyErr> for OszError {
+    fn from(err: PyErr) -> Self {
+        Self::Archive(err)
+    }
+}
+
+/// A single `.osu` file of an archive could not be read.
+#[derive(Debug)]
+pub enum EntryError {
+    Duplicate,
+    Limit(LimitExceeded),
+    Read(PyErr),
+}
+
+impl StdError for EntryError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Duplicate => None,
+            Self::Limit(err) => Some(err),
+            Self::Read(err) => Some(err),
+        }
+    }
+}
+
+impl Display for EntryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Duplicate => f.write_str("the archive contains multiple files of this name"),
+            Self::Limit(_) => f.write_str("limit exceeded"),
+            Self::Read(_) => f.write_str("failed to read file from archive"),
+        }
+    }
+}
+
+/// A `.osu` file of an archive.
+pub struct OszEntry {
+    pub name: String,
+    pub content: Result<Vec<u8>, EntryError>,
+}
+
+/// Reads all `.osu` files of a `zipfile.ZipFile` in archive order.
+///
+/// Files whose name occurs more than once are all reported as
+/// [`EntryError::Duplicate`] instead of one replacing the other.
+pub fn osu_files(
+    archive: &Bound<'_, PyAny>,
+    limits: &ParseLimits,
+    max_total_size: Option<u64>,
+) -> Result<Vec<OszEntry>, OszError> {
+    let py = archive.py();
+    let mut entries: Vec<OszEntry> = Vec::new();
+    let mut total_size = 0;
+
+    for info in archive.call_method0(intern!(py, "infolist"))?.try_iter()? {
+        let info = info?;
+        let name: String = info.getattr(intern!(py, "filename"))?.extract()?;
+
+        if !name.to_ascii_lowercase().ends_with(".osu") {
+            continue;
+        }
+
+        if let Some(entry) = entries.iter_mut().find(|entry| entry.name == name) {
+            entry.content = Err(EntryError::Duplicate);
+
+            continue;
+        }
+
+        // The size is specified by the archive itself but python's `zipfile`
+        // won't decompress more than that
+        let size: u64 = info.getattr(intern!(py, "file_size"))?.extract()?;
+        let remaining = max_total_size.map(|limit| limit.saturating_sub(total_size));
+
+        if let Some(limit) = max_total_size.filter(|_| remaining.is_some_and(|r| size > r)) {
+            return Err(OszError::TotalSize { limit });
+        }
+
+        if let Some(limit) = limits.max_file_size.filter(|limit| size > *limit) {
+            entries.push(OszEntry {
+                name,
+                content: Err(EntryError::Limit(LimitExceeded::FileSize { size, limit })),
+            });
+
+            continue;
+        }
+
+        let max_size = match (limits.max_file_size, remaining) {
+            (Some(limit), Some(remaining)) => Some(limit.min(remaining)),
+            (limit, remaining) => limit.or(remaining),
+        };
+
+        let content = match read_entry(archive, &info, max_size) {
+            Ok(content) => content,
+            Err(err) => {
+                entries.push(OszEntry {
+                    name,
+                    content: Err(EntryError::Read(err)),
+                });
+
+                continue;
+            }
+        };
+
+        let content = match content {
+            Ok(content) => {
+                total_size += content.len() as u64;
+
+                Ok(content)
+            }
+            Err(LimitExceeded::FileSize { size, .. })
+                if remaining.is_some_and(|remaining| size > remaining) =>
+            {
+                return Err(OszError::TotalSize {
+                    limit: max_total_size.unwrap_or_default(),
+                });
+            }
+            Err(err) => Err(EntryError::Limit(err)),
+        };
+
+        entries.push(OszEntry { name, content });
+    }
+
+    Ok(entries)
+}
+
+fn read_entry(
+    archive: &Bound<'_, PyAny>,
+    info: &Bound<'_, PyAny>,
+    max_size: Option<u64>,
+) -> PyResult<Result<Vec<u8>, LimitExceeded>> {
+    let py = archive.py();
+    let file = archive.call_method1(intern!(py, "open"), (info,))?;
+    let res = buffer::read_file(&file, max_size);
+    file.call_method0(intern!(py, "close"))?;
+
+    res
+}
@@ -0,0 +1,37 @@
+use pyo3::pyclass;
+
+use crate::decode::{self, ParseWarning};
+
+define_class! {
+    #[pyclass(name = "ParseWarning", frozen, skip_from_py_object)]
+    #[derive(Clone, PartialEq)]
+    pub struct PyParseWarning {
+        pub section: String!,
+        pub line_number: usize!,
+        pub line: String!,
+        pub reason: String!,
+    }
+}
+
+impl PyParseWarning {
+    /// Message when emitting the warning through Python's `warnings` module.
+    pub(crate) fn message(&self) -> String {
+        format!(
+            "Skipped line {} in section [{}]: {}",
+            self.line_number, self.section, self.reason
+        )
+    }
+}
+
+impl From<ParseWarning> for PyParseWarning {
+    fn from(warning: ParseWarning) -> Self {
+        let ParseWarning { location, reason } = warning;
+
+        Self {
+            section: decode::section_name(location.section).to_owned(),
+            line_number: location.line_number,
+            line: location.line,
+            reason,
+        }
+    }
+}
@@ -140,9 +140,16 @@ impl PyPerformance {
             ));
         };
 
+        // Mods and settings are applied beforehand so the GIL can be released
         perf = self.apply(perf, py)?;
-        let state = perf.generate_state();
-        let mut attrs = PyPerformanceAttributes::from(perf.calculate());
+
+        let (state, attrs) = py.detach(|| {
+            let state = perf.generate_state();
+
+            (state, perf.calculate())
+        });
+
+        let mut attrs = PyPerformanceAttributes::from(attrs);
         attrs.state = Some(state.into());
 
         Ok(attrs)
@@ -274,6 +281,169 @@ impl PyPerformance {
             self.hitresult_generators = [hitresult_generator; 4];
         }
     }
+
+    #[getter]
+    fn get_mods(&self, py: Python<'_>) -> Option<Py<PyAny>> {
+        self.difficulty.get_mods(py)
+    }
+
+    #[getter]
+    fn get_lazer(&self) -> Option<bool> {
+        self.difficulty.get_lazer()
+    }
+
+    #[getter]
+    fn get_clock_rate(&self) -> Option<f64> {
+        self.difficulty.get_clock_rate()
+    }
+
+    #[getter]
+    fn get_ar(&self) -> Option<f32> {
+        self.difficulty.get_ar()
+    }
+
+    #[getter]
+    fn get_fixed_ar(&self) -> bool {
+        self.difficulty.get_fixed_ar()
+    }
+
+    #[getter]
+    fn get_cs(&self) -> Option<f32> {
+        self.difficulty.get_cs()
+    }
+
+    #[getter]
+    fn get_fixed_cs(&self) -> bool {
+        self.difficulty.get_fixed_cs()
+    }
+
+    #[getter]
+    fn get_hp(&self) -> Option<f32> {
+        self.difficulty.get_hp()
+    }
+
+    #[getter]
+    fn get_fixed_hp(&self) -> bool {
+        self.difficulty.get_fixed_hp()
+    }
+
+    #[getter]
+    fn get_od(&self) -> Option<f32> {
+        self.difficulty.get_od()
+    }
+
+    #[getter]
+    fn get_fixed_od(&self) -> bool {
+        self.difficulty.get_fixed_od()
+    }
+
+    #[getter]
+    fn get_passed_objects(&self) -> Option<u32> {
+        self.difficulty.get_passed_objects()
+    }
+
+    #[getter]
+    fn get_hardrock_offsets(&self) -> Option<bool> {
+        self.difficulty.get_hardrock_offsets()
+    }
+
+    #[getter]
+    fn get_accuracy(&self) -> Option<f64> {
+        self.accuracy
+    }
+
+    #[getter]
+    fn get_combo(&self) -> Option<u32> {
+        self.combo
+    }
+
+    #[getter]
+    fn get_large_tick_hits(&self) -> Option<u32> {
+        self.large_tick_hits
+    }
+
+    #[getter]
+    fn get_small_tick_hits(&self) -> Option<u32> {
+        self.small_tick_hits
+    }
+
+    #[getter]
+    fn get_slider_end_hits(&self) -> Option<u32> {
+        self.slider_end_hits
+    }
+
+    #[getter]
+    fn get_n_geki(&self) -> Option<u32> {
+        self.n_geki
+    }
+
+    #[getter]
+    fn get_n_katu(&self) -> Option<u32> {
+        self.n_katu
+    }
+
+    #[getter]
+    fn get_n300(&self) -> Option<u32> {
+        self.n300
+    }
+
+    #[getter]
+    fn get_n100(&self) -> Option<u32> {
+        self.n100
+    }
+
+    #[getter]
+    fn get_n50(&self) -> Option<u32> {
+        self.n50
+    }
+
+    #[getter]
+    fn get_misses(&self) -> Option<u32> {
+        self.misses
+    }
+
+    #[getter]
+    fn get_legacy_total_score(&self) -> Option<u32> {
+        self.legacy_total_score
+    }
+
+    #[getter]
+    fn get_hitresult_priority(&self) -> PyHitResultPriority {
+        self.hitresult_priority
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        self.difficulty.fill_dict(&dict)?;
+
+        if let Some(accuracy) = self.accuracy {
+            dict.set_item("accuracy", accuracy)?;
+        }
+
+        let counts = [
+            ("combo", self.combo),
+            ("large_tick_hits", self.large_tick_hits),
+            ("small_tick_hits", self.small_tick_hits),
+            ("slider_end_hits", self.slider_end_hits),
+            ("n_geki", self.n_geki),
+            ("n_katu", self.n_katu),
+            ("n300", self.n300),
+            ("n100", self.n100),
+            ("n50", self.n50),
+            ("misses", self.misses),
+            ("legacy_total_score", self.legacy_total_score),
+        ];
+
+        for (key, count) in counts {
+            if let Some(count) = count {
+                dict.set_item(key, count)?;
+            }
+        }
+
+        dict.set_item("hitresult_priority", self.hitresult_priority)?;
+
+        Ok(dict)
+    }
 }
 
 impl PyPerformance {
@@ -0,0 +1,527 @@
+//! Compact binary representation of a [`PyBeatmap`] used for pickling.
+
+use std::num::NonZeroI32;
+
+use rosu_pp::{
+    model::{
+        beatmap::BreakPeriod,
+        control_point::{DifficultyPoint, EffectPoint, TimingPoint},
+        hit_object::{
+            HitObject, HitObjectKind, HitSoundType, HoldNote, PathControlPoint, PathType, Pos,
+            Slider, Spinner, SplineType,
+        },
+        mode::GameMode,
+    },
+    Beatmap,
+};
+
+use crate::{beatmap::PyBeatmap, metadata::PyBeatmapMetadata, parse_warning::PyParseWarning};
+
+const MAGIC: &[u8; 4] = b"RPPB";
+
+/// Must be bumped whenever the layout changes after a release so that states
+/// of a different layout are rejected instead of being misread.
+///
+/// The current layout is the beatmap itself followed by its time signatures,
+/// metadata, optional MD5 digest, and parse warnings.
+const FORMAT_VERSION: u8 = 1;
+
+/// The state could not be deserialized.
+pub struct InvalidState;
+
+pub enum UnpickleError {
+    Invalid,
+    /// The state was created with a different layout.
+    UnsupportedVersion(u8),
+}
+
+impl From<InvalidState> for UnpickleError {
+    fn from(_: InvalidState) -> Self {
+        Self::Invalid
+    }
+}
+
+pub fn serialize(map: &PyBeatmap) -> Vec<u8> {
+    let PyBeatmap {
+        inner,
+        time_signatures,
+        metadata,
+        md5,
+        parse_warnings,
+    } = map;
+
+    let mut w = Writer(Vec::with_capacity(64 + inner.hit_objects.len() * 32));
+    w.0.extend_from_slice(MAGIC);
+    w.u8(FORMAT_VERSION);
+
+    w.i32(inner.version);
+    w.bool(inner.is_convert);
+    w.f32(inner.stack_leniency);
+    w.u8(inner.mode as u8);
+    w.f32(inner.ar);
+    w.f32(inner.cs);
+    w.f32(inner.hp);
+    w.f32(inner.od);
+    w.f64(inner.slider_multiplier);
+    w.f64(inner.slider_tick_rate);
+
+    w.seq(&inner.breaks, |w, period| {
+        w.f64(period.start_time);
+        w.f64(period.end_time);
+    });
+
+    w.seq(&inner.timing_points, |w, point| {
+        w.f64(point.time);
+        w.f64(point.beat_len);
+    });
+
+    w.seq(&inner.difficulty_points, |w, point| {
+        w.f64(point.time);
+        w.f64(point.slider_velocity);
+        w.f64(point.bpm_multiplier);
+        w.bool(point.generate_ticks);
+    });
+
+    w.seq(&inner.effect_points, |w, point| {
+        w.f64(point.time);
+        w.bool(point.kiai);
+        w.f64(point.scroll_speed);
+    });
+
+    w.seq(&inner.hit_objects, Writer::hit_object);
+    w.seq(&inner.hit_sounds, |w, sound| w.u8(u8::from(*sound)));
+
+    w.seq(time_signatures, |w, (time, meter)| {
+        w.f64(*time);
+        w.i32(*meter);
+    });
+
+    let PyBeatmapMetadata {
+        title,
+        title_unicode,
+        artist,
+        artist_unicode,
+        creator,
+        version,
+        source,
+        tags,
+        beatmap_id,
+        beatmap_set_id,
+        audio_file,
+        audio_lead_in,
+        preview_time,
+    } = metadata;
+
+    for s in [
+        title,
+        title_unicode,
+        artist,
+        artist_unicode,
+        creator,
+        version,
+        source,
+        tags,
+        audio_file,
+    ] {
+        w.str(s);
+    }
+
+    w.i32(*beatmap_id);
+    w.i32(*beatmap_set_id);
+    w.f64(*audio_lead_in);
+    w.i32(*preview_time);
+
+    w.bool(md5.is_some());
+
+    if let Some(md5) = md5 {
+        w.str(md5);
+    }
+
+    w.seq(parse_warnings, |w, warning| {
+        w.str(&warning.section);
+        w.u32(warning.line_number as u32);
+        w.str(&warning.line);
+        w.str(&warning.reason);
+    });
+
+    w.0
+}
+
+pub fn deserialize(bytes: &[u8]) -> Result<PyBeatmap, UnpickleError> {
+    let mut r = Reader(bytes);
+
+    if r.take::<4>()? != *MAGIC {
+        return Err(UnpickleError::Invalid);
+    }
+
+    match r.u8()? {
+        FORMAT_VERSION => {}
+        version => return Err(UnpickleError::UnsupportedVersion(version)),
+    }
+
+    let inner = Beatmap {
+        version: r.i32()?,
+        is_convert: r.bool()?,
+        stack_leniency: r.f32()?,
+        mode: match r.u8()? {
+            mode @ 0..=3 => GameMode::from(mode),
+            _ => return Err(UnpickleError::Invalid),
+        },
+        ar: r.f32()?,
+        cs: r.f32()?,
+        hp: r.f32()?,
+        od: r.f32()?,
+        slider_multiplier: r.f64()?,
+        slider_tick_rate: r.f64()?,
+        breaks: r.seq(|r| {
+            Ok(BreakPeriod {
+                start_time: r.f64()?,
+                end_time: r.f64()?,
+            })
+        })?,
+        timing_points: r.seq(|r| {
+            Ok(TimingPoint {
+                time: r.f64()?,
+                beat_len: r.f64()?,
+            })
+        })?,
+        difficulty_points: r.seq(|r| {
+            Ok(DifficultyPoint {
+                time: r.f64()?,
+                slider_velocity: r.f64()?,
+                bpm_multiplier: r.f64()?,
+                generate_ticks: r.bool()?,
+            })
+        })?,
+        effect_points: r.seq(|r| {
+            Ok(EffectPoint {
+                time: r.f64()?,
+                kiai: r.bool()?,
+                scroll_speed: r.f64()?,
+            })
+        })?,
+        hit_objects: r.seq(Reader::hit_object)?,
+        hit_sounds: r.seq(|r| r.u8().map(HitSoundType::from))?,
+    };
+
+    let time_signatures = r.seq(|r| Ok((r.f64()?, r.i32()?)))?;
+
+    let metadata = PyBeatmapMetadata {
+        title: r.str()?,
+        title_unicode: r.str()?,
+        artist: r.str()?,
+        artist_unicode: r.str()?,
+        creator: r.str()?,
+        version: r.str()?,
+        source: r.str()?,
+        tags: r.str()?,
+        audio_file: r.str()?,
+        beatmap_id: r.i32()?,
+        beatmap_set_id: r.i32()?,
+        audio_lead_in: r.f64()?,
+        preview_time: r.i32()?,
+    };
+
+    let md5 = if r.bool()? { Some(r.str()?) } else { None };
+
+    let parse_warnings = r.seq(|r| {
+        Ok(PyParseWarning {
+            section: r.str()?,
+            line_number: r.u32()? as usize,
+            line: r.str()?,
+            reason: r.str()?,
+        })
+    })?;
+
+    if !r.0.is_empty() {
+        return Err(UnpickleError::Invalid);
+    }
+
+    Ok(PyBeatmap {
+        inner,
+        time_signatures,
+        metadata,
+        md5,
+        parse_warnings,
+    })
+}
+
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, n: u8) {
+        self.0.push(n);
+    }
+
+    fn bool(&mut self, b: bool) {
+        self.u8(u8::from(b));
+    }
+
+    fn u32(&mut self, n: u32) {
+        self.0.extend_from_slice(&n.to_le_bytes());
+    }
+
+    fn i32(&mut self, n: i32) {
+        self.0.extend_from_slice(&n.to_le_bytes());
+    }
+
+    fn f32(&mut self, n: f32) {
+        self.0.extend_from_slice(&n.to_le_bytes());
+    }
+
+    fn f64(&mut self, n: f64) {
+        self.0.extend_from_slice(&n.to_le_bytes());
+    }
+
+    fn str(&mut self, s: &str) {
+        self.u32(s.len() as u32);
+        self.0.extend_from_slice(s.as_bytes());
+    }
+
+    fn seq<T>(&mut self, items: &[T], f: impl Fn(&mut Self, &T)) {
+        self.u32(items.len() as u32);
+
+        for item in items {
+            f(self, item);
+        }
+    }
+
+    fn hit_object(&mut self, h: &HitObject) {
+        self.f32(h.pos.x);
+        self.f32(h.pos.y);
+        self.f64(h.start_time);
+
+        match h.kind {
+            HitObjectKind::Circle => self.u8(0),
+            HitObjectKind::Slider(ref slider) => {
+                self.u8(1);
+
+                match slider.expected_dist {
+                    Some(dist) => {
+                        self.bool(true);
+                        self.f64(dist);
+                    }
+                    None => self.bool(false),
+                }
+
+                self.u32(slider.repeats as u32);
+                self.seq(&slider.control_points, Self::control_point);
+                self.seq(&slider.node_sounds, |w, sound| w.u8(u8::from(*sound)));
+            }
+            HitObjectKind::Spinner(Spinner { duration }) => {
+                self.u8(2);
+                self.f64(duration);
+            }
+            HitObjectKind::Hold(HoldNote { duration }) => {
+                self.u8(3);
+                self.f64(duration);
+            }
+        }
+    }
+
+    fn control_point(&mut self, point: &PathControlPoint) {
+        self.f32(point.pos.x);
+        self.f32(point.pos.y);
+
+        let Some(path_type) = point.path_type else {
+            return self.u8(0);
+        };
+
+        let kind = match path_type.kind {
+            SplineType::Catmull => 1,
+            SplineType::BSpline => 2,
+            SplineType::Linear => 3,
+            SplineType::PerfectCurve => 4,
+        };
+
+        self.u8(kind);
+        self.i32(path_type.degree.map_or(0, NonZeroI32::get));
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], InvalidState> {
+        let (bytes, rest) = self.0.split_first_chunk::<N>().ok_or(InvalidState)?;
+        self.0 = rest;
+
+        Ok(*bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, InvalidState> {
+        self.take::<1>().map(|[n]| n)
+    }
+
+    fn bool(&mut self) -> Result<bool, InvalidState> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(InvalidState),
+        }
+    }
+
+    fn u32(&mut self) -> Result<u32, InvalidState> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn i32(&mut self) -> Result<i32, InvalidState> {
+        self.take().map(i32::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Result<f32, InvalidState> {
+        self.take().map(f32::from_le_bytes)
+    }
+
+    fn f64(&mut self) -> Result<f64, InvalidState> {
+        self.take().map(f64::from_le_bytes)
+    }
+
+    fn str(&mut self) -> Result<String, InvalidState> {
+        let len = self.u32()? as usize;
+        let (bytes, rest) = self.0.split_at_checked(len).ok_or(InvalidState)?;
+        self.0 = rest;
+
+        String::from_utf8(bytes.to_vec()).map_err(|_| InvalidState)
+    }
+
+    fn seq<T>(
+        &mut self,
+        f: impl Fn(&mut Self) -> Result<T, InvalidState>,
+    ) -> Result<Vec<T>, InvalidState> {
+        let len = self.u32()? as usize;
+
+        // Each item takes at least one byte; prevents huge allocations for
+        // corrupted lengths
+        if len > self.0.len() {
+            return Err(InvalidState);
+        }
+
+        (0..len).map(|_| f(self)).collect()
+    }
+
+    fn hit_object(&mut self) -> Result<HitObject, InvalidState> {
+        let pos = Pos::new(self.f32()?, self.f32()?);
+        let start_time = self.f64()?;
+
+        let kind = match self.u8()? {
+            0 => HitObjectKind::Circle,
+            1 => {
+                let expected_dist = if self.bool()? {
+                    Some(self.f64()?)
+                } else {
+                    None
+                };
+
+                HitObjectKind::Slider(Slider {
+                    expected_dist,
+                    repeats: self.u32()? as usize,
+                    control_points: self.seq(Self::control_point)?.into_boxed_slice(),
+                    node_sounds: self
+                        .seq(|r| r.u8().map(HitSoundType::from))?
+                        .into_boxed_slice(),
+                })
+            }
+            2 => HitObjectKind::Spinner(Spinner {
+                duration: self.f64()?,
+            }),
+            3 => HitObjectKind::Hold(HoldNote {
+                duration: self.f64()?,
+            }),
+            _ => return Err(InvalidState),
+        };
+
+        Ok(HitObject {
+            pos,
+            start_time,
+            kind,
+        })
+    }
+
+    fn control_point(&mut self) -> Result<PathControlPoint, InvalidState> {
+        let pos = Pos::new(self.f32()?, self.f32()?);
+
+        let kind = match self.u8()? {
+            0 => return Ok(PathControlPoint::new(pos)),
+            1 => SplineType::Catmull,
+            2 => SplineType::BSpline,
+            3 => SplineType::Linear,
+            4 => SplineType::PerfectCurve,
+            _ => return Err(InvalidState),
+        };
+
+        let path_type = PathType {
+            kind,
+            degree: NonZeroI32::new(self.i32()?),
+        };
+
+        Ok(PathControlPoint {
+            pos,
+            path_type: Some(path_type),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = include_str!("../tests/fixtures/test_map.osu");
+
+    fn map() -> PyBeatmap {
+        let decoded = rosu_map::from_str::<crate::decode::DecodedBeatmap>(MAP).unwrap();
+
+        PyBeatmap::decoded(decoded, "0123456789abcdef0123456789abcdef".to_owned())
+    }
+
+    #[test]
+    fn roundtrip() {
+        let map = map();
+        let deserialized = deserialize(&serialize(&map)).ok().unwrap();
+
+        assert_eq!(map.inner, deserialized.inner);
+        assert_eq!(map.time_signatures, deserialized.time_signatures);
+        assert_eq!(map.metadata, deserialized.metadata);
+        assert_eq!(map.md5, deserialized.md5);
+        assert_eq!(map.parse_warnings, deserialized.parse_warnings);
+    }
+
+    #[test]
+    fn roundtrip_without_md5() {
+        let map = PyBeatmap { md5: None, ..map() };
+        let deserialized = deserialize(&serialize(&map)).ok().unwrap();
+
+        assert_eq!(deserialized.md5, None);
+    }
+
+    #[test]
+    fn roundtrip_convert() {
+        let mut map = map();
+        map.inner
+            .convert_mut(GameMode::Mania, &Default::default())
+            .unwrap();
+        let deserialized = deserialize(&serialize(&map)).ok().unwrap();
+
+        assert!(deserialized.inner.is_convert);
+        assert_eq!(map.inner, deserialized.inner);
+    }
+
+    #[test]
+    fn rejects_truncated() {
+        let bytes = serialize(&map());
+
+        assert!(deserialize(&bytes[..bytes.len() - 1]).is_err());
+        assert!(deserialize(&[]).is_err());
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut bytes = serialize(&map());
+        bytes[MAGIC.len()] = FORMAT_VERSION + 1;
+
+        assert!(matches!(
+            deserialize(&bytes),
+            Err(UnpickleError::UnsupportedVersion(version)) if version == FORMAT_VERSION + 1
+        ));
+    }
+}
@@ -0,0 +1,393 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use pyo3::pyclass;
+use rosu_pp::{
+    model::{
+        beatmap::TooSuspicious,
+        hit_object::{HitObject, HitObjectKind},
+        mode::GameMode,
+    },
+    Beatmap,
+};
+
+// The thresholds below mirror those of rosu-pp's `TooSuspicious`; the tests
+// check them against `Beatmap::check_suspicion`
+
+const OBJECT_COUNT: usize = 500_000;
+const OBJECT_COUNT_TAIKO: usize = 30_000;
+const LENGTH_MS: f64 = (60 * 60 * 24 * 1000) as f64;
+const DENSITY_1S: usize = 200;
+const DENSITY_10S: usize = 500;
+const SLIDER_POS: f32 = 10_000.0;
+const SLIDER_REPEATS: usize = 1000;
+const SLIDER_CUTOFF: usize = 128;
+
+define_class! {
+    #[pyclass(name = "SuspicionReport", frozen, skip_from_py_object)]
+    #[derive(Clone)]
+    pub struct PySuspicionReport {
+        pub reason: PySuspicionReason!,
+        pub value: f64!,
+        pub threshold: f64!,
+        pub time: f64?,
+        pub message: String!,
+    }
+}
+
+impl PySuspicionReport {
+    pub(crate) fn new(reason: TooSuspicious, map: &Beatmap) -> Self {
+        match reason {
+            TooSuspicious::ObjectCount => {
+                let threshold = if map.mode == GameMode::Taiko {
+                    OBJECT_COUNT_TAIKO
+                } else {
+                    OBJECT_COUNT
+                };
+
+                let n_objects = map.hit_objects.len();
+
+                Self {
+                    reason: PySuspicionReason::ObjectCount,
+                    value: n_objects as f64,
+                    threshold: threshold as f64,
+                    time: None,
+                    message: format!(
+                        "the map has {n_objects} objects which exceeds the limit of {threshold}"
+                    ),
+                }
+            }
+            TooSuspicious::Length => {
+                let length = match map.hit_objects.as_slice() {
+                    [first, .., last] => last.start_time - first.start_time,
+                    _ => 0.0,
+                };
+
+                Self {
+                    reason: PySuspicionReason::Length,
+                    value: length,
+                    threshold: LENGTH_MS,
+                    time: None,
+                    message: format!(
+                        "the map is {length}ms long which exceeds the limit of {LENGTH_MS}ms"
+                    ),
+                }
+            }
+            TooSuspicious::Density => Self::density(map),
+            TooSuspicious::SliderPositions => {
+                let count = Self::suspicious_sliders(map, |h, repeats| {
+                    repeats <= SLIDER_REPEATS && suspicious_pos(h)
+                });
+
+                Self {
+                    reason: PySuspicionReason::SliderPositions,
+                    value: count as f64,
+                    threshold: SLIDER_CUTOFF as f64,
+                    time: None,
+                    message: format!(
+                        "{count} sliders are positioned beyond {SLIDER_POS} which exceeds \
+                        the limit of {SLIDER_CUTOFF} sliders"
+                    ),
+                }
+            }
+            TooSuspicious::SliderRepeats => {
+                let count = Self::suspicious_sliders(map, |_, repeats| repeats > SLIDER_REPEATS);
+
+                Self {
+                    reason: PySuspicionReason::SliderRepeats,
+                    value: count as f64,
+                    threshold: SLIDER_CUTOFF as f64,
+                    time: None,
+                    message: format!(
+                        "{count} sliders have more than {SLIDER_REPEATS} repeats which \
+                        exceeds the limit of {SLIDER_CUTOFF} sliders"
+                    ),
+                }
+            }
+            _ => Self::red_flag(map),
+        }
+    }
+
+    fn density(map: &Beatmap) -> Self {
+        let keys_per_hand = match map.mode {
+            GameMode::Taiko => 2,
+            GameMode::Mania => (map.cs as usize / 2).max(1),
+            GameMode::Osu | GameMode::Catch => 1,
+        };
+
+        let hit_objects = &map.hit_objects;
+
+        let limits = [
+            (DENSITY_1S * keys_per_hand, 1000.0),
+            (DENSITY_10S * keys_per_hand, 10_000.0),
+        ];
+
+        // Same order as rosu-pp: the first object that starts a too dense
+        // window is the offending one
+        let dense = (0..hit_objects.len()).find_map(|i| {
+            limits.into_iter().find_map(|(threshold, window)| {
+                let last = hit_objects.get(i + threshold)?;
+                let time = hit_objects[i].start_time;
+
+                (last.start_time - time < window).then_some((time, threshold, window))
+            })
+        });
+
+        if let Some((time, threshold, window)) = dense {
+            let count = hit_objects
+                .iter()
+                .skip_while(|h| h.start_time < time)
+                .take_while(|h| h.start_time - time < window)
+                .count();
+
+            let secs = window / 1000.0;
+
+            return Self {
+                reason: PySuspicionReason::Density,
+                value: count as f64,
+                threshold: threshold as f64,
+                time: Some(time),
+                message: format!(
+                    "{count} objects within {secs}s at {time}ms which exceeds the limit of \
+                    {threshold}"
+                ),
+            };
+        }
+
+        Self {
+            reason: PySuspicionReason::Density,
+            value: 0.0,
+            threshold: DENSITY_1S as f64,
+            time: None,
+            message: "notes are too dense".to_owned(),
+        }
+    }
+
+    fn red_flag(map: &Beatmap) -> Self {
+        let slider = map.hit_objects.iter().find_map(|h| match h.kind {
+            HitObjectKind::Slider(ref slider)
+                if slider.repeats > SLIDER_REPEATS && suspicious_pos(h) =>
+            {
+                Some((h, slider.repeats))
+            }
+            _ => None,
+        });
+
+        let Some((h, repeats)) = slider else {
+            return Self {
+                reason: PySuspicionReason::RedFlag,
+                value: 0.0,
+                threshold: 0.0,
+                time: None,
+                message: "the map seems too suspicious for further calculation".to_owned(),
+            };
+        };
+
+        Self {
+            reason: PySuspicionReason::RedFlag,
+            value: repeats as f64,
+            threshold: SLIDER_REPEATS as f64,
+            time: Some(h.start_time),
+            message: format!(
+                "slider at {}ms has {repeats} repeats and is positioned at ({}, {})",
+                h.start_time, h.pos.x, h.pos.y
+            ),
+        }
+    }
+
+    fn suspicious_sliders(map: &Beatmap, f: impl Fn(&HitObject, usize) -> bool) -> usize {
+        map.hit_objects
+            .iter()
+            .filter(|h| match h.kind {
+                HitObjectKind::Slider(ref slider) => f(h, slider.repeats),
+                _ => false,
+            })
+            .count()
+    }
+}
+
+fn suspicious_pos(h: &HitObject) -> bool {
+    h.pos.x.abs() > SLIDER_POS || h.pos.y.abs() > SLIDER_POS
+}
+
+#[pyclass(eq, eq_int, name = "SuspicionReason", from_py_object)]
+#[derive(Copy, Clone, Default, PartialEq)]
+pub enum PySuspicionReason {
+    /// Notes are too dense time-wise.
+    #[default]
+    Density,
+    /// The map seems too long.
+    Length,
+    /// Too many objects.
+    ObjectCount,
+    /// General red flag.
+    RedFlag,
+    /// Too many sliders' positions were suspicious.
+    SliderPositions,
+    /// Too many sliders had a very high amount of repeats.
+    SliderRepeats,
+}
+
+impl Debug for PySuspicionReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.__pyo3__repr__())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_pp::model::hit_object::{Pos, Slider};
+
+    use super::*;
+
+    fn circle(start_time: f64) -> HitObject {
+        HitObject {
+            pos: Pos::new(256.0, 192.0),
+            start_time,
+            kind: HitObjectKind::Circle,
+        }
+    }
+
+    fn slider(start_time: f64, x: f32, repeats: usize) -> HitObject {
+        HitObject {
+            pos: Pos::new(x, 192.0),
+            start_time,
+            kind: HitObjectKind::Slider(Slider {
+                expected_dist: None,
+                repeats,
+                control_points: Box::default(),
+                node_sounds: Box::default(),
+            }),
+        }
+    }
+
+    fn map(mode: GameMode, hit_objects: Vec<HitObject>) -> Beatmap {
+        Beatmap {
+            mode,
+            hit_objects,
+            ..Beatmap::default()
+        }
+    }
+
+    fn circles(mode: GameMode, count: usize, spacing: f64) -> Beatmap {
+        let hit_objects = (0..count).map(|i| circle(i as f64 * spacing)).collect();
+
+        map(mode, hit_objects)
+    }
+
+    fn sliders(count: usize, x: f32, repeats: usize) -> Beatmap {
+        let hit_objects = (0..count)
+            .map(|i| slider(i as f64 * 100.0, x, repeats))
+            .collect();
+
+        map(GameMode::Osu, hit_objects)
+    }
+
+    /// Asserts that rosu-pp accepts `below` and flags `above` and that the
+    /// report agrees with rosu-pp that the threshold was exceeded.
+    ///
+    /// Guards the thresholds copied from rosu-pp against drifting apart.
+    #[track_caller]
+    fn assert_boundary(below: Beatmap, above: Beatmap, reason: PySuspicionReason) {
+        assert!(below.check_suspicion().is_ok());
+
+        let report = PySuspicionReport::new(above.check_suspicion().unwrap_err(), &above);
+
+        assert_eq!(report.reason, reason);
+        assert!(report.value > report.threshold);
+    }
+
+    #[test]
+    fn object_count() {
+        assert_boundary(
+            circles(GameMode::Osu, OBJECT_COUNT, 100.0),
+            circles(GameMode::Osu, OBJECT_COUNT + 1, 100.0),
+            PySuspicionReason::ObjectCount,
+        );
+
+        assert_boundary(
+            circles(GameMode::Taiko, OBJECT_COUNT_TAIKO, 100.0),
+            circles(GameMode::Taiko, OBJECT_COUNT_TAIKO + 1, 100.0),
+            PySuspicionReason::ObjectCount,
+        );
+    }
+
+    #[test]
+    fn length() {
+        assert_boundary(
+            map(GameMode::Osu, vec![circle(0.0), circle(LENGTH_MS)]),
+            map(GameMode::Osu, vec![circle(0.0), circle(LENGTH_MS + 1.0)]),
+            PySuspicionReason::Length,
+        );
+    }
+
+    #[test]
+    fn density() {
+        assert_boundary(
+            circles(GameMode::Osu, DENSITY_1S + 1, 1000.0 / DENSITY_1S as f64),
+            circles(GameMode::Osu, DENSITY_1S + 1, 999.0 / DENSITY_1S as f64),
+            PySuspicionReason::Density,
+        );
+
+        assert_boundary(
+            circles(
+                GameMode::Osu,
+                DENSITY_10S + 1,
+                10_000.0 / DENSITY_10S as f64,
+            ),
+            circles(GameMode::Osu, DENSITY_10S + 1, 9999.0 / DENSITY_10S as f64),
+            PySuspicionReason::Density,
+        );
+
+        let mania = |spacing| Beatmap {
+            cs: 8.0,
+            ..circles(GameMode::Mania, 4 * DENSITY_1S + 1, spacing)
+        };
+
+        assert_boundary(
+            mania(1000.0 / (4 * DENSITY_1S) as f64),
+            mania(999.0 / (4 * DENSITY_1S) as f64),
+            PySuspicionReason::Density,
+        );
+    }
+
+    #[test]
+    fn slider_positions() {
+        let x = SLIDER_POS + 1.0;
+
+        assert_boundary(
+            sliders(SLIDER_CUTOFF, x, 0),
+            sliders(SLIDER_CUTOFF + 1, x, 0),
+            PySuspicionReason::SliderPositions,
+        );
+
+        assert!(sliders(SLIDER_CUTOFF + 1, SLIDER_POS, 0)
+            .check_suspicion()
+            .is_ok());
+    }
+
+    #[test]
+    fn slider_repeats() {
+        let repeats = SLIDER_REPEATS + 1;
+
+        assert_boundary(
+            sliders(SLIDER_CUTOFF, 256.0, repeats),
+            sliders(SLIDER_CUTOFF + 1, 256.0, repeats),
+            PySuspicionReason::SliderRepeats,
+        );
+
+        assert!(sliders(SLIDER_CUTOFF + 1, 256.0, SLIDER_REPEATS)
+            .check_suspicion()
+            .is_ok());
+    }
+
+    #[test]
+    fn red_flag() {
+        let x = SLIDER_POS + 1.0;
+
+        assert_boundary(
+            sliders(1, x, SLIDER_REPEATS),
+            sliders(1, x, SLIDER_REPEATS + 1),
+            PySuspicionReason::RedFlag,
+        );
+    }
+}
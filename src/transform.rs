@@ -0,0 +1,180 @@
+//! Transformations that modify the objects and timing of a [`PyBeatmap`].
+
+use std::ops::Range;
+
+use rosu_pp::model::{
+    hit_object::{HitObject, HitObjectKind},
+    mode::GameMode,
+};
+
+use crate::{beatmap::PyBeatmap, mania};
+
+/// Width of the playfield in osu!pixels.
+const PLAYFIELD_WIDTH: f32 = 512.0;
+
+/// Height of the playfield in osu!pixels.
+const PLAYFIELD_HEIGHT: f32 = 384.0;
+
+/// Divides all times by the clock rate so that the map plays at the given
+/// rate without any mods.
+pub fn scale_time(map: &mut PyBeatmap, clock_rate: f64) {
+    let inner = &mut map.inner;
+
+    for h in inner.hit_objects.iter_mut() {
+        h.start_time /= clock_rate;
+
+        match h.kind {
+            HitObjectKind::Circle | HitObjectKind::Slider(_) => {}
+            HitObjectKind::Spinner(ref mut spinner) => spinner.duration /= clock_rate,
+            HitObjectKind::Hold(ref mut hold) => hold.duration /= clock_rate,
+        }
+    }
+
+    // Slider durations depend on the beat length so they're adjusted too
+    for point in inner.timing_points.iter_mut() {
+        point.time /= clock_rate;
+        point.beat_len /= clock_rate;
+    }
+
+    for point in inner.difficulty_points.iter_mut() {
+        point.time /= clock_rate;
+    }
+
+    for point in inner.effect_points.iter_mut() {
+        point.time /= clock_rate;
+    }
+
+    for period in inner.breaks.iter_mut() {
+        period.start_time /= clock_rate;
+        period.end_time /= clock_rate;
+    }
+
+    for (time, _) in map.time_signatures.iter_mut() {
+        *time /= clock_rate;
+    }
+
+    let metadata = &mut map.metadata;
+    metadata.audio_lead_in /= clock_rate;
+
+    if metadata.preview_time >= 0 {
+        metadata.preview_time = (f64::from(metadata.preview_time) / clock_rate) as i32;
+    }
+}
+
+/// Flips objects vertically, just like the HardRock mod does.
+pub fn flip_vertical(map: &mut PyBeatmap) {
+    for h in map.inner.hit_objects.iter_mut() {
+        h.pos.y = PLAYFIELD_HEIGHT - h.pos.y;
+
+        if let HitObjectKind::Slider(ref mut slider) = h.kind {
+            for point in slider.control_points.iter_mut() {
+                point.pos.y = -point.pos.y;
+            }
+        }
+    }
+}
+
+/// Mirrors objects horizontally.
+///
+/// For osu!mania the columns are mirrored instead.
+pub fn mirror_horizontal(map: &mut PyBeatmap) {
+    if map.inner.mode == GameMode::Mania {
+        let total_columns = mania::total_columns(&map.inner);
+
+        for h in map.inner.hit_objects.iter_mut() {
+            mirror_column(h, total_columns);
+        }
+
+        return;
+    }
+
+    for h in map.inner.hit_objects.iter_mut() {
+        h.pos.x = PLAYFIELD_WIDTH - h.pos.x;
+
+        if let HitObjectKind::Slider(ref mut slider) = h.kind {
+            for point in slider.control_points.iter_mut() {
+                point.pos.x = -point.pos.x;
+            }
+        }
+    }
+}
+
+fn mirror_column(h: &mut HitObject, total_columns: f32) {
+    let divisor = PLAYFIELD_WIDTH / total_columns;
+    let column = mania::column(h.pos.x, total_columns) as f32;
+    let mirrored = total_columns - 1.0 - column;
+
+    h.pos.x = (mirrored * divisor + divisor / 2.0).floor();
+}
+
+/// Only keeps hit objects that start within `start..end`.
+pub fn slice(map: &mut PyBeatmap, start: f64, end: f64) {
+    let inner = &mut map.inner;
+    let in_range = |h: &HitObject| h.start_time >= start && h.start_time < end;
+
+    if inner.hit_sounds.len() == inner.hit_objects.len() {
+        let mut keep = inner.hit_objects.iter().map(in_range);
+        inner.hit_sounds.retain(|_| keep.next().unwrap_or(false));
+    }
+
+    inner.hit_objects.retain(in_range);
+    prune(map, start, end);
+}
+
+/// Only keeps hit objects within the given index range.
+///
+/// The range must be within bounds.
+pub fn slice_objects(map: &mut PyBeatmap, range: Range<usize>) {
+    let inner = &mut map.inner;
+
+    let start = inner.hit_objects.get(range.start).map(|h| h.start_time);
+    let end = range
+        .end
+        .checked_sub(1)
+        .and_then(|i| inner.hit_objects.get(i))
+        .map(|h| h.start_time);
+
+    if inner.hit_sounds.len() == inner.hit_objects.len() {
+        inner.hit_sounds.truncate(range.end);
+        inner.hit_sounds.drain(..range.start);
+    }
+
+    inner.hit_objects.truncate(range.end);
+    inner.hit_objects.drain(..range.start);
+
+    match start.zip(end) {
+        // The start time of the last object is still part of the range
+        Some((start, end)) => prune(map, start, end.next_up()),
+        None => prune(map, 0.0, 0.0),
+    }
+}
+
+/// Removes control points that have no effect within `start..end` and clips
+/// breaks to that range.
+///
+/// The control points that are active at `start` are kept so that the
+/// remaining hit objects are unaffected.
+fn prune(map: &mut PyBeatmap, start: f64, end: f64) {
+    fn retain_active<T>(points: &mut Vec<T>, start: f64, end: f64, time: impl Fn(&T) -> f64) {
+        let first = points
+            .partition_point(|point| time(point) <= start)
+            .saturating_sub(1);
+
+        points.truncate(points.partition_point(|point| time(point) < end));
+        points.drain(..first.min(points.len()));
+    }
+
+    let inner = &mut map.inner;
+
+    retain_active(&mut inner.timing_points, start, end, |point| point.time);
+    retain_active(&mut inner.difficulty_points, start, end, |point| point.time);
+    retain_active(&mut inner.effect_points, start, end, |point| point.time);
+    retain_active(&mut map.time_signatures, start, end, |(time, _)| *time);
+
+    inner.breaks.retain_mut(|period| {
+        period.start_time = period.start_time.max(start);
+        period.end_time = period.end_time.min(end);
+
+        period.start_time < period.end_time
+    });
+}
@@ -1,8 +1,20 @@
+import copy
+import hashlib
+import io
+import os
+import pickle
+import warnings
+import zipfile
+
 import pytest
 
 import rosu_pp_py as rosu
 from rosu_pp_py import ArgsError, ParseError
 
+from .conftest import FIXTURES
+
+TEST_MAP = FIXTURES / "test_map.osu"
+
 
 class TestBeatmapCreation:
     def test_from_path(self, osu_map):
@@ -86,6 +98,551 @@ class TestBeatmapProperties:
         assert "Beatmap" in repr(map)
 
 
+class TestBeatmapMetadata:
+    def test_metadata(self, osu_map):
+        metadata = osu_map.metadata
+        assert metadata.title == "re[in]flaw"
+        assert metadata.title_unicode == "re[in]flaw"
+        assert metadata.artist == "MYUKKE."
+        assert metadata.creator == "captin1"
+        assert metadata.version == "toybot's Expert"
+        assert metadata.source == ""
+        assert metadata.tags.startswith("fa featured artist")
+        assert metadata.beatmap_id == 2785319
+        assert metadata.beatmap_set_id == 1344871
+
+    def test_general(self, osu_map):
+        metadata = osu_map.metadata
+        assert metadata.audio_file == "audio.mp3"
+        assert metadata.audio_lead_in == 0.0
+        assert metadata.preview_time == 25309
+
+    def test_defaults(self):
+        metadata = rosu.Beatmap(content="osu file format v14\n").metadata
+        assert metadata.title == ""
+        assert metadata.beatmap_id == -1
+        assert metadata.preview_time == -1
+
+    def test_preserved_by_pickle(self, osu_map):
+        map = pickle.loads(pickle.dumps(osu_map))
+        assert map.metadata.title == osu_map.metadata.title
+        assert map.metadata.beatmap_id == osu_map.metadata.beatmap_id
+
+
+class TestBeatmapInfo:
+    @pytest.fixture
+    def info(self):
+        return rosu.Beatmap.read_info(path=str(TEST_MAP))
+
+    def test_matches_beatmap(self, info, osu_map):
+        assert info.mode == osu_map.mode
+        assert info.format_version == osu_map.version
+        assert info.difficulty_name == osu_map.metadata.version
+        assert info.ar == osu_map.ar
+        assert info.cs == osu_map.cs
+        assert info.hp == osu_map.hp
+        assert info.od == osu_map.od
+        assert info.n_objects == osu_map.n_objects
+        assert info.n_circles == osu_map.n_circles
+        assert info.n_sliders == osu_map.n_sliders
+        assert info.n_spinners == osu_map.n_spinners
+        assert info.n_holds == osu_map.n_holds
+
+    def test_sources(self, info):
+        with open(TEST_MAP, "rb") as f:
+            content = f.read()
+        assert repr(rosu.Beatmap.read_info(bytes=content)) == repr(info)
+        assert repr(rosu.Beatmap.read_info(content=content.decode())) == repr(info)
+        assert repr(rosu.Beatmap.read_info(file=io.BytesIO(content))) == repr(info)
+
+    def test_mania(self):
+        content = "osu file format v14\n\n[General]\nMode: 3\n\n[Difficulty]\nCircleSize:7\n\n[HitObjects]\n36,192,1000,1,0,0:0:0:0:\n109,192,1500,128,0,2000:0:0:0:0:\n"
+        info = rosu.Beatmap.read_info(content=content)
+        assert info.mode == rosu.GameMode.Mania
+        assert info.cs == 7
+        assert info.n_objects == 2
+        assert info.n_holds == 1
+
+    def test_max_file_size(self):
+        with pytest.raises(ParseError):
+            rosu.Beatmap.read_info(path=str(TEST_MAP), max_file_size=100)
+
+    def test_no_source(self):
+        with pytest.raises(ArgsError):
+            rosu.Beatmap.read_info()
+        with pytest.raises(ArgsError):
+            rosu.Beatmap.read_info(path=str(TEST_MAP), strict=True)
+
+
+class TestBeatmapParseMany:
+    @pytest.fixture
+    def song_dir(self, tmp_path):
+        content = TEST_MAP.read_bytes()
+        (tmp_path / "sub").mkdir()
+        (tmp_path / "a.osu").write_bytes(content)
+        (tmp_path / "sub" / "b.OSU").write_bytes(content)
+        (tmp_path / "notes.txt").write_bytes(b"not a map")
+        return tmp_path
+
+    def test_parse_dir(self, song_dir, osu_map):
+        maps = rosu.Beatmap.parse_dir(song_dir)
+        assert sorted(maps) == [str(song_dir / "a.osu"), str(song_dir / "sub" / "b.OSU")]
+        for map in maps.values():
+            assert map.n_objects == osu_map.n_objects
+
+    def test_parse_dir_not_recursive(self, song_dir):
+        maps = rosu.Beatmap.parse_dir(song_dir, recursive=False, threads=1)
+        assert list(maps) == [str(song_dir / "a.osu")]
+
+    def test_parse_dir_missing(self, tmp_path):
+        with pytest.raises(OSError):
+            rosu.Beatmap.parse_dir(tmp_path / "missing")
+
+    def test_parse_dir_unreadable_subdir(self, song_dir):
+        sub = song_dir / "sub"
+        sub.chmod(0)
+        try:
+            try:
+                os.listdir(sub)
+            except PermissionError:
+                pass
+            else:
+                pytest.skip("permissions are not enforced")
+            maps = rosu.Beatmap.parse_dir(song_dir)
+        finally:
+            sub.chmod(0o755)
+        assert isinstance(maps[str(song_dir / "a.osu")], rosu.Beatmap)
+        assert isinstance(maps[str(sub)], ParseError)
+
+    def test_parse_many(self, song_dir):
+        paths = [song_dir / "a.osu", str(song_dir / "missing.osu")]
+        maps = rosu.Beatmap.parse_many(paths, threads=2)
+        assert isinstance(maps[str(song_dir / "a.osu")], rosu.Beatmap)
+        assert isinstance(maps[str(song_dir / "missing.osu")], ParseError)
+
+    def test_invalid_threads(self, song_dir):
+        with pytest.raises(ArgsError):
+            rosu.Beatmap.parse_dir(song_dir, threads=0)
+        with pytest.raises(ArgsError, match="at most"):
+            rosu.Beatmap.parse_many([], threads=1_000_000)
+
+
+class TestBeatmapMania:
+    CONTENT = "osu file format v14\n\n[General]\nMode: 3\n\n[Difficulty]\nCircleSize:4\n\n[HitObjects]\n64,192,1000,1,0,0:0:0:0:\n64,192,1500,128,0,2000:0:0:0:0:\n448,192,2500,1,0,0:0:0:0:\n448,192,3000,1,0,0:0:0:0:\n"
+
+    def test_not_mania(self, osu_map):
+        assert osu_map.mania_keys is None
+        assert osu_map.mania_columns is None
+        assert osu_map.mania_ln_ratio is None
+
+    def test_columns(self):
+        map = rosu.Beatmap(content=self.CONTENT)
+        assert map.mania_keys == 4
+        columns = map.mania_columns
+        assert [c.column for c in columns] == [0, 1, 2, 3]
+        assert [c.notes for c in columns] == [1, 0, 0, 2]
+        assert [c.holds for c in columns] == [1, 0, 0, 0]
+        assert map.mania_ln_ratio == 0.25
+
+    def test_convert_key_mod(self, osu_map):
+        map = osu_map.converted(rosu.GameMode.Mania, "6K")
+        assert map.mania_keys == 6
+        columns = map.mania_columns
+        assert len(columns) == 6
+        assert sum(c.notes + c.holds for c in columns) == map.n_objects
+
+
+class TestBeatmapBpm:
+    CONTENT = "osu file format v14\n\n[TimingPoints]\n0,500,4,2,0,100,1,0\n1000,250,4,2,0,100,1,0\n3000,500,4,2,0,100,1,0\n9000,100,4,2,0,100,1,0\n\n[HitObjects]\n256,192,0,1,0\n256,192,5000,1,0\n"
+
+    def test_stats(self):
+        stats = rosu.Beatmap(content=self.CONTENT).bpm_stats()
+        assert stats.min == 120
+        assert stats.max == 240
+        assert stats.most_common == 120
+        assert stats.clock_rate == 1
+
+    def test_stats_mods(self):
+        stats = rosu.Beatmap(content=self.CONTENT).bpm_stats("DT")
+        assert stats.min == 180
+        assert stats.max == 360
+        assert stats.clock_rate == 1.5
+
+    def test_single_bpm(self, osu_map):
+        stats = osu_map.bpm_stats()
+        assert stats.min == stats.max == stats.most_common
+        assert stats.most_common == pytest.approx(osu_map.bpm)
+
+    def test_timeline(self):
+        map = rosu.Beatmap(content=self.CONTENT)
+        assert map.bpm_timeline() == [(0, 120), (1000, 240), (3000, 120)]
+        assert map.bpm_timeline(clock_rate=2) == [(0, 240), (500, 480), (1500, 240)]
+
+    def test_invalid_clock_rate(self, osu_map):
+        with pytest.raises(ArgsError):
+            osu_map.bpm_stats(clock_rate=0)
+
+
+class TestBeatmapMd5:
+    @pytest.fixture
+    def expected(self):
+        with open(TEST_MAP, "rb") as f:
+            return hashlib.md5(f.read()).hexdigest()
+
+    def test_sources(self, osu_map, osu_map_bytes, osu_map_content, expected):
+        assert osu_map.md5 == expected
+        assert osu_map_bytes.md5 == expected
+        assert osu_map_content.md5 == expected
+
+    def test_file(self, expected):
+        with open(TEST_MAP, "rb") as f:
+            assert rosu.Beatmap(file=f).md5 == expected
+
+    def test_osz(self, tmp_path, expected):
+        maps = rosu.Beatmap.from_osz(create_osz(tmp_path / "set.osz"))
+        assert maps["Artist - Title (Mapper) [Easy].osu"].md5 == expected
+        assert maps["Artist - Title (Mapper) [Hard].osu"].md5 != expected
+
+    def test_parse_many(self, expected):
+        maps = rosu.Beatmap.parse_many([str(TEST_MAP)] * 3, threads=2)
+        assert all(map.md5 == expected for map in maps.values())
+
+    def test_empty(self):
+        assert rosu.Beatmap(content="").md5 == "d41d8cd98f00b204e9800998ecf8427e"
+
+    def test_preserved(self, osu_map, expected):
+        assert osu_map.copy().md5 == expected
+        assert copy.deepcopy(osu_map).md5 == expected
+        assert pickle.loads(pickle.dumps(osu_map)).md5 == expected
+
+    def test_transformed(self, osu_map, expected):
+        assert osu_map.converted(rosu.GameMode.Mania).md5 is None
+        assert osu_map.convert_all()[rosu.GameMode.Taiko].md5 is None
+        assert osu_map.with_clock_rate(1.5).md5 is None
+        assert osu_map.with_attributes(ar=10).md5 is None
+        assert osu_map.slice(3000, 6000).md5 is None
+        assert osu_map.slice_objects(0, 10).md5 is None
+        assert osu_map.flipped().md5 is None
+        assert osu_map.mirrored().md5 is None
+        assert pickle.loads(pickle.dumps(osu_map.flipped())).md5 is None
+        assert osu_map.md5 == expected
+
+        osu_map.convert(rosu.GameMode.Catch)
+        assert osu_map.md5 is None
+
+
+class TestBeatmapHitObjects:
+    @pytest.fixture
+    def hit_objects(self, osu_map):
+        return osu_map.hit_objects
+
+    def test_count(self, osu_map, hit_objects):
+        assert len(hit_objects) == osu_map.n_objects
+
+    def test_kinds(self, osu_map, hit_objects):
+        circles = [h for h in hit_objects if h.kind == rosu.HitObjectKind.Circle]
+        sliders = [h for h in hit_objects if h.kind == rosu.HitObjectKind.Slider]
+        assert len(circles) == osu_map.n_circles
+        assert len(sliders) == osu_map.n_sliders
+
+    def test_sorted(self, hit_objects):
+        times = [h.start_time for h in hit_objects]
+        assert times == sorted(times)
+
+    def test_circle(self, hit_objects):
+        circle = hit_objects[0]
+        assert circle.kind == rosu.HitObjectKind.Circle
+        assert circle.start_time == 2810.0
+        assert circle.end_time == circle.start_time
+        assert (circle.x, circle.y) == (34.0, 31.0)
+        assert circle.repeats is None
+        assert circle.control_points is None
+
+    def test_slider(self, hit_objects):
+        slider = next(h for h in hit_objects if h.kind == rosu.HitObjectKind.Slider)
+        assert slider.start_time == 3868.0
+        assert slider.end_time > slider.start_time
+        assert slider.repeats == 0
+        assert slider.length == pytest.approx(85.0)
+        assert slider.control_points[0].path_type == "P"
+        assert (slider.control_points[1].x, slider.control_points[1].y) == (51.0, -21.0)
+
+
+class TestBeatmapControlPoints:
+    def test_timing_points(self, osu_map):
+        points = osu_map.timing_points
+        assert len(points) == 1
+        assert points[0].time == 2810.0
+        assert points[0].bpm == pytest.approx(170.0)
+        assert points[0].meter == 4
+
+    def test_difficulty_points(self, osu_map):
+        points = osu_map.difficulty_points
+        assert [p.time for p in points] == [13751.0, 14104.0, 19398.0, 19751.0]
+        assert points[0].slider_velocity == pytest.approx(0.2)
+        assert points[-1].slider_velocity == pytest.approx(1.0)
+
+    def test_effect_points(self):
+        content = (
+            "osu file format v14\n"
+            "[TimingPoints]\n"
+            "0,500,3,2,0,60,1,1\n"
+            "1000,300,5,2,0,60,1,0\n"
+        )
+        map = rosu.Beatmap(content=content)
+        assert [p.meter for p in map.timing_points] == [3, 5]
+        assert [p.kiai for p in map.effect_points] == [True, False]
+
+    def test_timing_points_same_time(self):
+        content = (
+            "osu file format v14\n"
+            "[TimingPoints]\n"
+            "0,500,3,2,0,60,1,0\n"
+            "0,300,5,2,0,60,1,0\n"
+            "1000,600,6,2,0,60,1,0\n"
+            "500,400,7,2,0,60,1,0\n"
+            "1000,250,2,2,0,60,1,0\n"
+        )
+        map = rosu.Beatmap(content=content)
+        points = [(p.time, p.beat_len, p.meter) for p in map.timing_points]
+        assert points == [(0.0, 500.0, 3), (500.0, 400.0, 7), (1000.0, 250.0, 2)]
+
+
+class TestBeatmapBreaks:
+    def test_breaks(self, osu_map):
+        breaks = osu_map.breaks
+        assert len(breaks) == osu_map.n_breaks
+        assert breaks[0].start_time == 48186.0
+        assert breaks[0].end_time == 51667.0
+        assert breaks[0].duration == 3481.0
+
+    def test_total_break_time(self, osu_map):
+        assert osu_map.total_break_time == sum(b.duration for b in osu_map.breaks)
+
+    def test_play_length(self, osu_map):
+        hit_objects = osu_map.hit_objects
+        expected = max(h.end_time for h in hit_objects) - hit_objects[0].start_time
+        assert osu_map.play_length == pytest.approx(expected)
+
+    def test_drain_time(self, osu_map):
+        expected = osu_map.play_length - osu_map.total_break_time
+        assert osu_map.drain_time == pytest.approx(expected)
+
+    def test_empty_map(self):
+        map = rosu.Beatmap(content="osu file format v14\n")
+        assert map.play_length == 0.0
+        assert map.drain_time == 0.0
+
+
+def map_with_objects(lines, mode=0, **kwargs):
+    content = "osu file format v14\n\n[General]\nMode: {}\n\n[HitObjects]\n{}".format(
+        mode, "\n".join(lines)
+    )
+    return rosu.Beatmap(content=content, **kwargs)
+
+
+class TestBeatmapLimits:
+    def test_within_limits(self):
+        map = rosu.Beatmap(
+            path=str(TEST_MAP),
+            max_file_size=1_000_000,
+            max_hit_objects=47,
+            max_slider_length=1000.0,
+            max_slider_repeats=10,
+            max_timing_points=5,
+        )
+        assert map.n_objects == 47
+
+    def test_file_size(self):
+        with pytest.raises(ParseError, match="file size"):
+            rosu.Beatmap(path=str(TEST_MAP), max_file_size=100)
+
+        with pytest.raises(ParseError, match="file size"):
+            rosu.Beatmap(content="osu file format v14\n", max_file_size=10)
+
+    def test_hit_objects(self):
+        with pytest.raises(ParseError, match="hit objects"):
+            rosu.Beatmap(path=str(TEST_MAP), max_hit_objects=46)
+
+    def test_slider_length(self):
+        with pytest.raises(ParseError, match="slider length"):
+            map_with_objects(["256,192,1000,2,0,L|300:192,1,5000"], max_slider_length=1000)
+
+    def test_slider_repeats(self):
+        with pytest.raises(ParseError, match="slider repeat"):
+            map_with_objects(["256,192,1000,2,0,L|300:192,101,100"], max_slider_repeats=99)
+
+        map = map_with_objects(["256,192,1000,2,0,L|300:192,100,100"], max_slider_repeats=99)
+        assert map.hit_objects[0].repeats == 99
+
+    def test_timing_points(self):
+        content = (
+            "osu file format v14\n\n[TimingPoints]\n"
+            "0,500,4,2,0,100,1,0\n1000,400,4,2,0,100,1,0\n"
+        )
+        with pytest.raises(ParseError, match="timing points"):
+            rosu.Beatmap(content=content, max_timing_points=1)
+
+    def test_invalid_limit(self):
+        with pytest.raises(TypeError):
+            rosu.Beatmap(path=str(TEST_MAP), max_hit_objects="a")
+
+
+class TestBeatmapParseError:
+    CONTENT = "osu file format v14\n\n[HitObjects]\n256,192,1000,1,0\n256,192,oops,1,0\n"
+    STORYBOARD = (
+        "osu file format v14\n\n[Events]\n"
+        "//Background and Video events\n"
+        '0,0,"bg.jpg",0,0\n'
+        "//Storyboard Layer 3 (Foreground)\n"
+        'Sprite,Foreground,Centre,"sb/star.png",320,240\n'
+        " F,0,1000,2000,0,1\n"
+        " _M,0,1000,2000,320,240,320,200\n"
+        " L,1000,2\n"
+        "  S,0,0,500,0.5,1\n"
+        "//Break Periods\n"
+        "2,5000,8000\n\n"
+        "[HitObjects]\n256,192,1000,1,0\n256,192,10000,1,0\n"
+    )
+
+    def test_lenient_by_default(self):
+        assert rosu.Beatmap(content=self.CONTENT).n_objects == 1
+
+    def test_parse_warnings(self):
+        map = rosu.Beatmap(content=self.CONTENT)
+        assert len(map.parse_warnings) == 1
+        warning = map.parse_warnings[0]
+        assert warning.section == "HitObjects"
+        assert warning.line_number == 5
+        assert warning.line == "256,192,oops,1,0"
+        assert warning.reason
+
+    def test_no_parse_warnings(self, osu_map):
+        assert osu_map.parse_warnings == []
+
+    def test_storyboard_no_parse_warnings(self):
+        map = rosu.Beatmap(content=self.STORYBOARD)
+        assert map.parse_warnings == []
+
+    def test_parse_warnings_pickle(self):
+        map = rosu.Beatmap(content=self.CONTENT)
+        unpickled = pickle.loads(pickle.dumps(map))
+        assert repr(unpickled.parse_warnings) == repr(map.parse_warnings)
+
+    def test_warn(self):
+        with warnings.catch_warnings(record=True) as caught:
+            warnings.simplefilter("always")
+            rosu.Beatmap(content=self.CONTENT, warn=True)
+        assert len(caught) == 1
+        assert issubclass(caught[0].category, UserWarning)
+        assert "line 5" in str(caught[0].message)
+
+    def test_storyboard_no_warn(self):
+        with warnings.catch_warnings(record=True) as caught:
+            warnings.simplefilter("always")
+            rosu.Beatmap(content=self.STORYBOARD, warn=True)
+        assert caught == []
+
+    def test_warn_as_error(self):
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            with pytest.raises(UserWarning):
+                rosu.Beatmap(content=self.CONTENT, warn=True)
+
+    def test_strict(self):
+        with pytest.raises(ParseError) as exc:
+            rosu.Beatmap(content=self.CONTENT, strict=True)
+        err = exc.value
+        assert err.section == "HitObjects"
+        assert err.line_number == 5
+        assert err.line == "256,192,oops,1,0"
+        assert err.causes[0] == "failed on line 5 in section [HitObjects]"
+        assert len(err.causes) > 1
+
+    def test_strict_storyboard(self):
+        map = rosu.Beatmap(content=self.STORYBOARD, strict=True)
+        assert map.n_objects == 2
+        assert [(b.start_time, b.end_time) for b in map.breaks] == [(5000.0, 8000.0)]
+
+    def test_limit_location(self):
+        with pytest.raises(ParseError) as exc:
+            rosu.Beatmap(path=str(TEST_MAP), max_hit_objects=3)
+        err = exc.value
+        assert err.section == "HitObjects"
+        assert err.line.startswith("0,225,3339,")
+        assert "hit objects" in err.causes[-1]
+
+    def test_no_location(self):
+        with pytest.raises(ParseError) as exc:
+            rosu.Beatmap(path="/nonexistent/path/file.osu")
+        err = exc.value
+        assert err.section is None
+        assert err.line_number is None
+        assert err.line is None
+        assert len(err.causes) == 1
+
+    def test_defaults(self):
+        err = ParseError("message")
+        assert err.section is None
+        assert err.causes == []
+
+    def test_causes_per_instance(self):
+        first = ParseError("first")
+        first.causes.append("cause")
+        assert ParseError("second").causes == []
+        assert first.causes == ["cause"]
+
+        with pytest.raises(ParseError) as exc:
+            rosu.Beatmap(content=self.CONTENT, strict=True)
+        assert exc.value.causes is not ParseError("third").causes
+
+
+class TestBeatmapSuspicion:
+    def test_not_suspicious(self, osu_map):
+        assert not osu_map.is_suspicious()
+        assert osu_map.check_suspicion() is None
+
+    def test_density(self):
+        map = map_with_objects(["256,192,{},1,0".format(1000 + i) for i in range(250)])
+        report = map.check_suspicion()
+        assert map.is_suspicious()
+        assert report.reason == rosu.SuspicionReason.Density
+        assert report.value == 250
+        assert report.threshold == 200
+        assert report.time == 1000.0
+        assert "250 objects" in report.message
+
+    def test_length(self):
+        map = map_with_objects(["256,192,0,1,0", "256,192,90000000,1,0"])
+        report = map.check_suspicion()
+        assert report.reason == rosu.SuspicionReason.Length
+        assert report.value == 90_000_000
+        assert report.threshold == 86_400_000
+        assert report.time is None
+
+    def test_object_count(self):
+        map = map_with_objects(["256,192,{},1,0".format(i * 1000) for i in range(30_001)], 1)
+        report = map.check_suspicion()
+        assert report.reason == rosu.SuspicionReason.ObjectCount
+        assert report.value == 30_001
+        assert report.threshold == 30_000
+
+    def test_red_flag(self):
+        map = map_with_objects(["20000,192,1000,2,0,L|300:192,1002,100"])
+        report = map.check_suspicion()
+        assert report.reason == rosu.SuspicionReason.RedFlag
+        assert report.value == 1001
+        assert report.threshold == 1000
+        assert report.time == 1000.0
+
+    def test_slider_repeats(self):
+        lines = ["256,192,{},2,0,L|300:192,1002,100".format(i * 1000) for i in range(130)]
+        report = map_with_objects(lines).check_suspicion()
+        assert report.reason == rosu.SuspicionReason.SliderRepeats
+        assert report.value == 130
+        assert report.threshold == 128
+
+
 class TestBeatmapConversion:
     def test_convert_to_mania(self, osu_map):
         osu_map.convert(rosu.GameMode.Mania, None)
@@ -94,3 +651,432 @@ class TestBeatmapConversion:
     def test_convert_with_mods(self, osu_map):
         osu_map.convert(rosu.GameMode.Taiko, "HD")
         assert osu_map.mode == rosu.GameMode.Taiko
+
+    def test_converted(self, osu_map):
+        map = osu_map.converted(rosu.GameMode.Catch)
+        assert map.mode == rosu.GameMode.Catch
+        assert map.is_convert
+        assert osu_map.mode == rosu.GameMode.Osu
+        assert not osu_map.is_convert
+
+    def test_converted_twice(self, osu_map):
+        map = osu_map.converted(rosu.GameMode.Taiko)
+        with pytest.raises(rosu.ConvertError):
+            map.converted(rosu.GameMode.Mania)
+
+    def test_convert_all(self, osu_map):
+        maps = osu_map.convert_all()
+        assert len(maps) == 4
+        for mode, map in maps.items():
+            assert map.mode == mode
+        assert not maps[rosu.GameMode.Osu].is_convert
+        assert maps[rosu.GameMode.Mania].is_convert
+
+    def test_convert_all_converted(self, osu_map):
+        osu_map.convert(rosu.GameMode.Taiko)
+        maps = osu_map.convert_all()
+        assert list(maps) == [rosu.GameMode.Taiko]
+
+    def test_copy(self, osu_map):
+        map = osu_map.copy()
+        map.convert(rosu.GameMode.Mania)
+        assert osu_map.mode == rosu.GameMode.Osu
+        assert osu_map.n_objects == 47
+
+
+class TestBeatmapTransforms:
+    def test_with_clock_rate(self, osu_map):
+        map = osu_map.with_clock_rate(1.5)
+        assert osu_map.hit_objects[0].start_time == 2810.0
+        assert map.hit_objects[0].start_time == pytest.approx(2810.0 / 1.5)
+        assert map.timing_points[0].bpm == pytest.approx(osu_map.bpm * 1.5)
+        assert map.breaks[0].duration == pytest.approx(osu_map.breaks[0].duration / 1.5)
+        assert map.ar > osu_map.ar
+        assert map.od > osu_map.od
+
+    def test_with_clock_rate_matches_dt(self, osu_map):
+        map = osu_map.with_attributes(ar=7, od=6)
+        expected = rosu.Difficulty(mods=64).calculate(map).stars
+        actual = rosu.Difficulty().calculate(map.with_clock_rate(1.5)).stars
+        assert actual == pytest.approx(expected, rel=1e-2)
+
+    def test_with_clock_rate_matches_clock_rate(self, osu_map):
+        # AR and OD stay below 10 so both ways match
+        map = osu_map.with_clock_rate(1.1)
+        assert map.ar < 10 and map.od < 10
+        expected = rosu.Difficulty(clock_rate=1.1).calculate(osu_map).stars
+        actual = rosu.Difficulty().calculate(map).stars
+        assert actual == pytest.approx(expected, rel=1e-3)
+
+    def test_with_clock_rate_capped(self, osu_map):
+        # AR and OD above 10 are capped when calculating the rate-edited map
+        map = osu_map.with_clock_rate(1.5)
+        assert map.ar > 10 and map.od > 10
+        capped = rosu.Difficulty().calculate(map.with_attributes(ar=10, od=10)).stars
+        assert rosu.Difficulty().calculate(map).stars == capped
+        assert capped < rosu.Difficulty(clock_rate=1.5).calculate(osu_map).stars
+        perf = rosu.Performance().calculate(map).pp
+        assert perf < rosu.Performance(clock_rate=1.5).calculate(osu_map).pp
+
+    def test_with_clock_rate_invalid(self, osu_map):
+        with pytest.raises(ArgsError):
+            osu_map.with_clock_rate(0)
+
+    def test_with_attributes(self, osu_map):
+        map = osu_map.with_attributes(ar=9.5, cs=3)
+        assert map.ar == 9.5
+        assert map.cs == 3.0
+        assert map.od == osu_map.od
+
+    def test_with_attributes_mods(self, osu_map):
+        map = osu_map.with_attributes(mods="HR")
+        assert map.cs == pytest.approx(osu_map.cs * 1.3)
+        assert map.ar == 10.0
+
+    def test_with_attributes_invalid_kwarg(self, osu_map):
+        with pytest.raises(ArgsError):
+            osu_map.with_attributes(clock_rate=1.5)
+
+    def test_flipped(self, osu_map):
+        map = osu_map.flipped()
+        assert map.hit_objects[0].y == 384.0 - osu_map.hit_objects[0].y
+        assert repr(map.flipped().hit_objects) == repr(osu_map.hit_objects)
+
+    def test_flipped_matches_hr(self, osu_map):
+        map = osu_map.flipped().with_attributes(mods="HR")
+        expected = rosu.Difficulty(mods="HR").calculate(osu_map).stars
+        assert rosu.Difficulty().calculate(map).stars == expected
+
+    def test_mirrored(self, osu_map):
+        map = osu_map.mirrored()
+        assert map.hit_objects[0].x == 512.0 - osu_map.hit_objects[0].x
+        assert repr(map.mirrored().hit_objects) == repr(osu_map.hit_objects)
+
+    def test_mirrored_mania(self, osu_map):
+        map = osu_map.converted(rosu.GameMode.Mania)
+        mirrored = map.mirrored()
+        keys = int(map.cs)
+
+        for h, m in zip(map.hit_objects, mirrored.hit_objects):
+            column = int(h.x * keys / 512)
+            assert int(m.x * keys / 512) == keys - 1 - column
+
+    def test_slice(self, osu_map):
+        map = osu_map.slice(3000, 6000)
+        assert map.n_objects == 19
+        assert all(3000 <= h.start_time < 6000 for h in map.hit_objects)
+        assert osu_map.n_objects == 47
+
+
+class TestBeatmapSlice:
+    def test_slice_standalone(self):
+        content = (
+            "osu file format v14\n\n[Events]\n2,1000,2000\n2,5000,6000\n\n"
+            "[TimingPoints]\n0,500,4,0,0,100,1,0\n1500,400,3,0,0,100,1,0\n"
+            "1600,-50,3,0,0,100,0,0\n4000,300,4,0,0,100,1,0\n\n"
+            "[HitObjects]\n"
+            + "\n".join(f"256,192,{t},1,0,0:0:0:0:" for t in range(0, 7000, 250))
+        )
+        map = rosu.Beatmap(content=content).slice(1800, 5500)
+        assert [p.time for p in map.timing_points] == [1500.0, 4000.0]
+        assert [p.meter for p in map.timing_points] == [3, 4]
+        assert [p.time for p in map.difficulty_points] == [1600.0, 4000.0]
+        assert [(b.start_time, b.end_time) for b in map.breaks] == [
+            (1800.0, 2000.0),
+            (5000.0, 5500.0),
+        ]
+
+    def test_slice_objects(self, osu_map):
+        map = osu_map.slice_objects(10, 30)
+        assert map.n_objects == 20
+        assert map.hit_objects[0].start_time == osu_map.hit_objects[10].start_time
+        assert len(map.timing_points) == 1
+        assert rosu.Difficulty().calculate(map).stars > 0
+
+    def test_slice_objects_matches_passed_objects(self, osu_map):
+        expected = rosu.Difficulty(passed_objects=20).calculate(osu_map)
+        actual = rosu.Difficulty().calculate(osu_map.slice_objects(0, 20))
+        assert actual.stars == expected.stars
+        assert actual.max_combo == expected.max_combo
+
+    def test_slice_objects_indices(self, osu_map):
+        assert osu_map.slice_objects(-5).n_objects == 5
+        assert osu_map.slice_objects(0, -7).n_objects == 40
+        assert osu_map.slice_objects(30, 10).n_objects == 0
+        assert osu_map.slice_objects(40, 100).n_objects == 7
+
+
+class TestBeatmapPickle:
+    def test_roundtrip(self, osu_map):
+        map = pickle.loads(pickle.dumps(osu_map))
+        assert map.mode == osu_map.mode
+        assert map.n_objects == osu_map.n_objects
+        assert map.ar == osu_map.ar
+        assert map.hit_objects[-1].end_time == osu_map.hit_objects[-1].end_time
+        assert map.timing_points[0].meter == osu_map.timing_points[0].meter
+        assert map.breaks[0].duration == osu_map.breaks[0].duration
+
+    def test_roundtrip_convert(self, osu_map):
+        osu_map.convert(rosu.GameMode.Mania, None)
+        map = pickle.loads(pickle.dumps(osu_map))
+        assert map.mode == rosu.GameMode.Mania
+        assert map.is_convert
+        assert map.cs == osu_map.cs
+
+    def test_difficulty_unchanged(self, osu_map):
+        map = pickle.loads(pickle.dumps(osu_map))
+        expected = rosu.Difficulty().calculate(osu_map)
+        assert rosu.Difficulty().calculate(map).stars == expected.stars
+
+    def test_deepcopy(self, osu_map):
+        map = copy.deepcopy(osu_map)
+        map.convert(rosu.GameMode.Taiko, None)
+        assert osu_map.mode == rosu.GameMode.Osu
+        assert map.mode == rosu.GameMode.Taiko
+
+    def test_invalid_state(self):
+        map = rosu.Beatmap(content="")
+        with pytest.raises(ParseError):
+            map.__setstate__(b"invalid")
+
+    def test_unsupported_version(self, osu_map):
+        state = bytearray(osu_map.__getstate__())
+        state[4] = 2
+        with pytest.raises(ParseError, match="unsupported format version 2"):
+            osu_map.__setstate__(bytes(state))
+
+    def test_reduce_skips_constructor(self, osu_map):
+        func, args = osu_map.__reduce__()
+        assert func == rosu.Beatmap._from_state
+        assert func(*args).n_objects == osu_map.n_objects
+
+
+class TestBeatmapEncode:
+    def test_header(self, osu_map):
+        content = osu_map.to_osu_string()
+        assert content.startswith("osu file format v14\n")
+
+    def test_roundtrip(self, osu_map):
+        map = rosu.Beatmap(content=osu_map.to_osu_string())
+        assert map.n_objects == osu_map.n_objects
+        assert map.ar == osu_map.ar
+        assert repr(map.metadata) == repr(osu_map.metadata)
+        assert repr(map.hit_objects) == repr(osu_map.hit_objects)
+        assert repr(map.timing_points) == repr(osu_map.timing_points)
+        assert repr(map.difficulty_points) == repr(osu_map.difficulty_points)
+        assert repr(map.effect_points) == repr(osu_map.effect_points)
+        assert repr(map.breaks) == repr(osu_map.breaks)
+
+    @pytest.mark.parametrize(
+        "mode",
+        [rosu.GameMode.Osu, rosu.GameMode.Taiko, rosu.GameMode.Catch, rosu.GameMode.Mania],
+    )
+    def test_difficulty_unchanged(self, osu_map, mode):
+        osu_map.convert(mode)
+
+        # Re-parsed taiko converts would be treated as regular taiko maps
+        if mode == rosu.GameMode.Taiko:
+            with pytest.raises(rosu.ConvertError):
+                osu_map.to_osu_string()
+            return
+
+        map = rosu.Beatmap(content=osu_map.to_osu_string())
+        assert map.mode == mode
+
+        for mods in [0, 16, 64]:
+            expected = rosu.Difficulty(mods=mods).calculate(osu_map)
+            actual = rosu.Difficulty(mods=mods).calculate(map)
+            assert actual.stars == expected.stars
+            assert actual.max_combo == expected.max_combo
+
+    def test_write(self, osu_map, tmp_path):
+        path = tmp_path / "map.osu"
+        osu_map.write(path)
+        assert path.read_text() == osu_map.to_osu_string()
+        assert rosu.Beatmap(path=path).n_objects == 47
+
+    def test_write_taiko_convert(self, osu_map, tmp_path):
+        path = tmp_path / "map.osu"
+        with pytest.raises(rosu.ConvertError):
+            osu_map.converted(rosu.GameMode.Taiko).write(path)
+        assert not path.exists()
+
+    def test_write_invalid_path(self, osu_map, tmp_path):
+        with pytest.raises(OSError):
+            osu_map.write(tmp_path / "missing" / "map.osu")
+
+
+class TestBeatmapSources:
+    def test_pathlib(self):
+        map = rosu.Beatmap(path=TEST_MAP)
+        assert map.n_objects == 47
+
+    def test_bytes(self, osu_map_bytes):
+        with open(TEST_MAP, "rb") as f:
+            content = f.read()
+
+        for value in [content, bytearray(content), memoryview(content)]:
+            assert rosu.Beatmap(bytes=value).n_objects == 47
+            assert rosu.Beatmap(content=value).n_objects == 47
+
+    def test_non_contiguous_buffer(self):
+        with open(TEST_MAP, "rb") as f:
+            content = f.read()
+
+        doubled = bytes(b for c in content for b in (c, 0))
+        view = memoryview(doubled)[::2]
+        assert rosu.Beatmap(bytes=view).n_objects == 47
+
+    def test_binary_file(self):
+        with open(TEST_MAP, "rb") as f:
+            map = rosu.Beatmap(file=f)
+        assert map.n_objects == 47
+
+    def test_text_file(self):
+        with open(TEST_MAP, encoding="utf-8") as f:
+            map = rosu.Beatmap(file=f)
+        assert map.n_objects == 47
+
+    def test_bytes_io(self):
+        with open(TEST_MAP, "rb") as f:
+            stream = io.BytesIO(f.read())
+        assert rosu.Beatmap(file=stream).n_objects == 47
+
+    def test_zip_file(self, tmp_path):
+        archive = tmp_path / "maps.zip"
+        with zipfile.ZipFile(archive, "w", zipfile.ZIP_DEFLATED) as z:
+            z.write(TEST_MAP, "test_map.osu")
+
+        with zipfile.ZipFile(archive) as z, z.open("test_map.osu") as f:
+            map = rosu.Beatmap(file=f)
+        assert map.n_objects == 47
+
+    def test_file_size_limit(self):
+        with open(TEST_MAP, "rb") as f:
+            with pytest.raises(ParseError, match="file size"):
+                rosu.Beatmap(file=f, max_file_size=100)
+
+    def test_invalid_file(self):
+        with pytest.raises(TypeError, match="readable file object"):
+            rosu.Beatmap(file=42)
+
+    def test_invalid_bytes(self):
+        with pytest.raises(TypeError):
+            rosu.Beatmap(bytes=42)
+
+
+def create_osz(path, compression=zipfile.ZIP_DEFLATED):
+    with open(TEST_MAP, "rb") as f:
+        content = f.read()
+
+    with zipfile.ZipFile(path, "w", compression) as z:
+        z.writestr("Artist - Title (Mapper) [Easy].osu", content)
+        hard = content.replace(b"CircleSize:4.5", b"CircleSize:5")
+        z.writestr("Artist - Title (Mapper) [Hard].osu", hard)
+        z.writestr("audio.mp3", b"\x00" * 100)
+        z.writestr("bg.jpg", b"\x00" * 100)
+
+    return path
+
+
+class TestBeatmapOsz:
+    def test_from_path(self, tmp_path):
+        maps = rosu.Beatmap.from_osz(create_osz(tmp_path / "set.osz"))
+        assert sorted(maps) == [
+            "Artist - Title (Mapper) [Easy].osu",
+            "Artist - Title (Mapper) [Hard].osu",
+        ]
+        assert maps["Artist - Title (Mapper) [Easy].osu"].n_objects == 47
+        assert maps["Artist - Title (Mapper) [Hard].osu"].cs == 5.0
+
+    def test_from_str_path(self, tmp_path):
+        maps = rosu.Beatmap.from_osz(str(create_osz(tmp_path / "set.osz")))
+        assert len(maps) == 2
+
+    def test_from_bytes(self, tmp_path):
+        path = create_osz(tmp_path / "set.osz")
+        maps = rosu.Beatmap.from_osz(path.read_bytes())
+        assert len(maps) == 2
+
+    def test_stored(self, tmp_path):
+        maps = rosu.Beatmap.from_osz(create_osz(tmp_path / "set.osz", zipfile.ZIP_STORED))
+        assert all(map.n_objects == 47 for map in maps.values())
+
+    def test_difficulty_matches(self, tmp_path, osu_map):
+        maps = rosu.Beatmap.from_osz(create_osz(tmp_path / "set.osz"))
+        map = maps["Artist - Title (Mapper) [Easy].osu"]
+        expected = rosu.Difficulty().calculate(osu_map).stars
+        assert rosu.Difficulty().calculate(map).stars == expected
+
+    def test_corrupt_entry(self, tmp_path):
+        path = tmp_path / "set.osz"
+        with zipfile.ZipFile(path, "w", zipfile.ZIP_STORED) as z:
+            z.writestr("good.osu", b"osu file format v14\n")
+            z.writestr("bad.osu", b"osu file format v14\n")
+
+        data = bytearray(path.read_bytes())
+        i = data.rindex(b"osu file format v14")
+        data[i] = ord("x")
+
+        maps = rosu.Beatmap.from_osz(bytes(data))
+        assert isinstance(maps["good.osu"], rosu.Beatmap)
+        assert isinstance(maps["bad.osu"], ParseError)
+        assert "CRC" in str(maps["bad.osu"])
+
+    def test_lying_size(self, tmp_path):
+        path = tmp_path / "set.osz"
+        with zipfile.ZipFile(path, "w", zipfile.ZIP_DEFLATED) as z:
+            z.writestr("map.osu", b"osu file format v14\n")
+
+        # Claim a decompressed size of almost 4GiB in the central directory
+        data = bytearray(path.read_bytes())
+        i = data.index(b"PK\x01\x02")
+        data[i + 24 : i + 28] = (0xFFFFFFF0).to_bytes(4, "little")
+
+        with pytest.raises(ParseError, match="total size limit"):
+            rosu.Beatmap.from_osz(bytes(data))
+
+        maps = rosu.Beatmap.from_osz(bytes(data), max_total_size=None, max_file_size=1000)
+        assert isinstance(maps["map.osu"], ParseError)
+
+    def test_limits(self, tmp_path):
+        path = create_osz(tmp_path / "set.osz")
+        maps = rosu.Beatmap.from_osz(path, max_hit_objects=10)
+        assert all(isinstance(map, ParseError) for map in maps.values())
+
+        maps = rosu.Beatmap.from_osz(path, max_file_size=100)
+        assert all(isinstance(map, ParseError) for map in maps.values())
+
+    def test_max_total_size(self, tmp_path):
+        path = create_osz(tmp_path / "set.osz")
+        size = TEST_MAP.stat().st_size
+        assert len(rosu.Beatmap.from_osz(path, max_total_size=3 * size)) == 2
+
+        with pytest.raises(ParseError, match="total size limit"):
+            rosu.Beatmap.from_osz(path, max_total_size=size + 1)
+
+    def test_duplicate_names(self, tmp_path):
+        path = tmp_path / "set.osz"
+        with warnings.catch_warnings():
+            warnings.simplefilter("ignore")
+            with zipfile.ZipFile(path, "w") as z:
+                z.writestr("map.osu", b"osu file format v14\n")
+                z.writestr("map.osu", b"osu file format v14\n")
+                z.writestr("other.osu", b"osu file format v14\n")
+
+        maps = rosu.Beatmap.from_osz(path)
+        assert isinstance(maps["map.osu"], ParseError)
+        assert "multiple files" in str(maps["map.osu"])
+        assert isinstance(maps["other.osu"], rosu.Beatmap)
+
+    def test_invalid_kwarg(self, tmp_path):
+        with pytest.raises(ArgsError):
+            rosu.Beatmap.from_osz(create_osz(tmp_path / "set.osz"), max_size=1)
+
+    def test_invalid_archive(self):
+        with pytest.raises(ParseError):
+            rosu.Beatmap.from_osz(b"not a zip archive")
+
+    def test_missing_file(self):
+        with pytest.raises(ParseError):
+            rosu.Beatmap.from_osz("/nonexistent/set.osz")
@@ -1,3 +1,5 @@
+from concurrent.futures import ThreadPoolExecutor
+
 import pytest
 
 import rosu_pp_py as rosu
@@ -76,6 +78,118 @@ class TestDifficultyCalculate:
         assert strains is not None
         assert strains.mode == rosu.GameMode.Osu
 
+    def test_calculate_threaded(self, osu_map):
+        diff = rosu.Difficulty(mods="DT")
+        expected = diff.calculate(osu_map).stars
+
+        with ThreadPoolExecutor(max_workers=4) as executor:
+            results = list(executor.map(lambda _: diff.calculate(osu_map), range(8)))
+
+        assert all(attrs.stars == expected for attrs in results)
+
+    def test_calculate_many(self, osu_map):
+        diff = rosu.Difficulty(mods="HR")
+        mania = osu_map.converted(rosu.GameMode.Mania)
+        maps = [osu_map, mania, osu_map]
+        results = diff.calculate_many(maps, threads=2)
+        assert [attrs.mode for attrs in results] == [
+            rosu.GameMode.Osu,
+            rosu.GameMode.Mania,
+            rosu.GameMode.Osu,
+        ]
+        assert [attrs.stars for attrs in results] == [
+            diff.calculate(map).stars for map in maps
+        ]
+
+    def test_calculate_many_empty(self, diff):
+        assert diff.calculate_many([]) == []
+
+    def test_calculate_many_invalid_threads(self, diff, osu_map):
+        with pytest.raises(ArgsError):
+            diff.calculate_many([osu_map], threads=0)
+
+    def test_calculate_mods(self, osu_map):
+        diff = rosu.Difficulty(mods="HD", lazer=False)
+        mods = ["", "HR", "DT", ["HD", "DT"], 8 + 16, {"acronym": "HT"}]
+        results = diff.calculate_mods(osu_map, mods)
+        assert [spec for spec, _ in results] == mods
+        expected = [
+            rosu.Difficulty(mods=m, lazer=False).calculate(osu_map).stars for m in mods
+        ]
+        stars = [attrs.stars for _, attrs in results]
+        assert stars == expected
+        assert stars[2] > stars[0] > stars[5]
+
+    def test_calculate_mods_same_combination(self, diff, osu_map):
+        results = diff.calculate_mods(osu_map, ["HDDT", ["HD", "DT"], 8 + 64])
+        assert len({repr(attrs) for _, attrs in results}) == 1
+
+    def test_calculate_mods_invalid(self, diff, osu_map):
+        with pytest.raises(TypeError):
+            diff.calculate_mods(osu_map, ["HR", [1.5]])
+
+    def test_stars_by_rate(self, osu_map):
+        diff = rosu.Difficulty(mods="HR")
+        rates = [0.75, 1.0, 1.25, 1.5]
+        results = diff.stars_by_rate(osu_map, rates)
+        expected = [
+            rosu.Difficulty(mods="HR", clock_rate=rate).calculate(osu_map).stars
+            for rate in rates
+        ]
+        assert [attrs.stars for attrs in results] == expected
+        assert expected == sorted(expected)
+
+    def test_stars_by_rate_invalid(self, diff, osu_map):
+        with pytest.raises(ArgsError):
+            diff.stars_by_rate(osu_map, [1.0, 0.0])
+
+
+class TestDifficultyGetters:
+    def test_defaults(self, diff):
+        assert diff.mods is None
+        assert diff.clock_rate is None
+        assert diff.ar is None
+        assert diff.fixed_ar is False
+        assert diff.passed_objects is None
+        assert diff.lazer is None
+        assert diff.to_dict() == {}
+
+    def test_specified(self):
+        diff = rosu.Difficulty(mods="HDDT", clock_rate=1.3, ar=9.5, fixed_ar=True, lazer=False)
+        assert diff.mods == "HDDT"
+        assert diff.clock_rate == pytest.approx(1.3)
+        assert diff.ar == pytest.approx(9.5)
+        assert diff.fixed_ar is True
+        assert diff.cs is None
+        assert diff.lazer is False
+
+    def test_setters(self, diff):
+        diff.set_od(8.0, False)
+        diff.set_passed_objects(10)
+        diff.set_hardrock_offsets(True)
+        assert diff.od == pytest.approx(8.0)
+        assert diff.fixed_od is False
+        assert diff.passed_objects == 10
+        assert diff.hardrock_offsets is True
+
+    def test_clamped(self, diff):
+        diff.set_clock_rate(1000.0)
+        assert diff.clock_rate == pytest.approx(100.0)
+
+    def test_to_dict_roundtrip(self, osu_map):
+        diff = rosu.Difficulty(mods=8 + 64, cs=4.0, passed_objects=5, hardrock_offsets=False)
+        d = diff.to_dict()
+        assert d == {
+            "mods": 8 + 64,
+            "cs": 4.0,
+            "fixed_cs": False,
+            "passed_objects": 5,
+            "hardrock_offsets": False,
+        }
+        copy = rosu.Difficulty(**d)
+        assert copy.to_dict() == d
+        assert copy.calculate(osu_map).stars == diff.calculate(osu_map).stars
+
 
 class TestDifficultyGradual:
     def test_gradual_difficulty(self, diff, osu_map):
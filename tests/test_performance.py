@@ -1,3 +1,5 @@
+from concurrent.futures import ThreadPoolExecutor
+
 import pytest
 
 import rosu_pp_py as rosu
@@ -99,6 +101,49 @@ class TestPerformanceSetters:
         perf.set_hitresult_generator(rosu.HitResultGenerator.Fast, None)
 
 
+class TestPerformanceGetters:
+    def test_defaults(self, perf):
+        assert perf.mods is None
+        assert perf.accuracy is None
+        assert perf.misses is None
+        assert perf.hitresult_priority == rosu.HitResultPriority.BestCase
+        assert perf.to_dict() == {"hitresult_priority": rosu.HitResultPriority.BestCase}
+
+    def test_specified(self, perf):
+        perf.set_mods("HR")
+        perf.set_ar(9.0, True)
+        perf.set_accuracy(98.5)
+        perf.set_n100(3)
+        perf.set_misses(1)
+        assert perf.mods == "HR"
+        assert perf.ar == pytest.approx(9.0)
+        assert perf.fixed_ar is True
+        assert perf.accuracy == pytest.approx(98.5)
+        assert perf.n100 == 3
+        assert perf.misses == 1
+        assert perf.combo is None
+
+    def test_to_dict_roundtrip(self, osu_map):
+        perf = rosu.Performance(
+            clock_rate=1.5,
+            lazer=False,
+            combo=100,
+            misses=2,
+            hitresult_priority=rosu.HitResultPriority.WorstCase,
+        )
+        d = perf.to_dict()
+        assert d == {
+            "clock_rate": 1.5,
+            "lazer": False,
+            "combo": 100,
+            "misses": 2,
+            "hitresult_priority": rosu.HitResultPriority.WorstCase,
+        }
+        copy = rosu.Performance(**d)
+        assert copy.to_dict() == d
+        assert copy.calculate(osu_map).pp == perf.calculate(osu_map).pp
+
+
 class TestPerformanceCalculate:
     def test_calculate_with_beatmap(self, perf, osu_map):
         attrs = perf.calculate(osu_map)
@@ -120,6 +165,15 @@ class TestPerformanceCalculate:
         assert attrs2 is not None
         assert attrs2.pp >= 0
 
+    def test_calculate_threaded(self, osu_map):
+        perf = rosu.Performance(accuracy=98.5, misses=1)
+        expected = perf.calculate(osu_map).pp
+
+        with ThreadPoolExecutor(max_workers=4) as executor:
+            results = list(executor.map(lambda _: perf.calculate(osu_map), range(8)))
+
+        assert all(attrs.pp == expected for attrs in results)
+
     def test_calculate_invalid_arg_raises(self, perf):
         with pytest.raises(ArgsError):
             perf.calculate("invalid")